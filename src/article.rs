@@ -0,0 +1,246 @@
+//! `article.md`, the written version of the walkthrough.
+//!
+//! Besides the text itself, this module pulls the Rust code blocks out of
+//! the article and checks each one the way its fence annotation says, so
+//! the examples stay correct as the article changes.
+
+use std::fmt;
+use std::io;
+
+use crate::compile_fail::{self, Case};
+use crate::rustc::{self, Compilation};
+
+/// The article as it was when the crate was built. `explain` quotes it; the
+/// `verify-article` and `sync` commands read the file on disk instead.
+pub const TEXT: &str = include_str!("../article.md");
+
+/// The paragraph of `text` that contains `needle`, with its lines joined.
+///
+/// A paragraph is a run of non-blank lines outside code fences.
+pub fn paragraph_containing(text: &str, needle: &str) -> Option<String> {
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut in_fence = false;
+    for line in text.lines().chain([""]) {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            paragraph.clear();
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if line.trim().is_empty() {
+            let joined = paragraph.join(" ");
+            if joined.contains(needle) {
+                return Some(joined);
+            }
+            paragraph.clear();
+        } else {
+            paragraph.push(line.trim());
+        }
+    }
+    None
+}
+
+/// How a code block of the article is checked, from its fence info string:
+/// ```` ```rust ````, ```` ```rust,no_run ````, ```` ```rust,should_fail,E0382 ````
+/// or ```` ```rust,ignore ````.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// Compile and run; the program must exit successfully.
+    Run,
+    /// Compile only.
+    NoRun,
+    /// Must not compile, and must report these error codes if any are given.
+    ShouldFail(Vec<String>),
+    /// Not checked.
+    Ignore,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Run => f.write_str("run"),
+            Mode::NoRun => f.write_str("no_run"),
+            Mode::ShouldFail(codes) if codes.is_empty() => f.write_str("should_fail"),
+            Mode::ShouldFail(codes) => write!(f, "should_fail {}", codes.join(" ")),
+            Mode::Ignore => f.write_str("ignore"),
+        }
+    }
+}
+
+/// A ```` ```rust ```` block of the article.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// The heading the block appears under.
+    pub heading: String,
+    /// Line of the opening fence, starting at 1.
+    pub line: usize,
+    /// Line of the closing fence.
+    pub end: usize,
+    pub mode: Mode,
+    /// The fence info string, e.g. `rust,no_run`.
+    pub info: String,
+    /// The code, without the fences and without common indentation.
+    pub code: String,
+}
+
+impl CodeBlock {
+    /// The block as a complete program: fragments are wrapped in `fn main`.
+    pub fn program(&self) -> String {
+        if self.code.contains("fn main") {
+            return self.code.clone();
+        }
+        let mut program = String::from("fn main() {\n");
+        for line in self.code.lines() {
+            if !line.is_empty() {
+                program.push_str("    ");
+            }
+            program.push_str(line);
+            program.push('\n');
+        }
+        program.push_str("}\n");
+        program
+    }
+}
+
+/// The Rust code blocks of `text`, in order. Blocks in other languages are skipped.
+pub fn code_blocks(text: &str) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut heading = String::new();
+    let mut open: Option<(usize, &str, Option<Mode>)> = None;
+    let mut code: Vec<&str> = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let trimmed = line.trim_start();
+        match &open {
+            None if trimmed.starts_with("```") => {
+                let info = trimmed[3..].trim();
+                open = Some((index + 1, info, mode(info)));
+                code.clear();
+            }
+            None if trimmed.starts_with('#') => {
+                heading = trimmed.trim_start_matches('#').trim().to_string();
+            }
+            None => {}
+            Some((start, info, mode)) if trimmed.starts_with("```") => {
+                if let Some(mode) = mode {
+                    blocks.push(CodeBlock {
+                        heading: heading.clone(),
+                        line: *start,
+                        end: index + 1,
+                        mode: mode.clone(),
+                        info: info.to_string(),
+                        code: dedent(&code),
+                    });
+                }
+                open = None;
+            }
+            Some(_) => code.push(line),
+        }
+    }
+    blocks
+}
+
+/// The mode of a fence info string, or `None` if it is not Rust.
+pub(crate) fn mode(info: &str) -> Option<Mode> {
+    let mut words = info.split(',').map(str::trim);
+    if words.next() != Some("rust") {
+        return None;
+    }
+    let mut mode = Mode::Run;
+    for word in words {
+        match word {
+            "no_run" => mode = Mode::NoRun,
+            "ignore" => mode = Mode::Ignore,
+            "should_fail" => mode = Mode::ShouldFail(Vec::new()),
+            code if code.starts_with('E') => {
+                if let Mode::ShouldFail(codes) = &mut mode {
+                    codes.push(code.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(mode)
+}
+
+/// Removes the indentation the lines have in common and trailing whitespace.
+pub(crate) fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut code = String::new();
+    for line in lines {
+        code.push_str(line.get(indent..).unwrap_or("").trim_end());
+        code.push('\n');
+    }
+    code
+}
+
+/// Why a code block did not behave as its annotation says.
+#[derive(Debug)]
+pub enum BlockFailure {
+    /// The block should compile but does not.
+    DoesNotCompile(Compilation),
+    /// The block compiled and ran, but the program failed.
+    Crashed(String),
+    /// A `should_fail` block was not rejected with its expected codes.
+    NotRejected(compile_fail::Failure),
+    Rustc(io::Error),
+}
+
+impl fmt::Display for BlockFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockFailure::DoesNotCompile(compilation) => {
+                write!(f, "does not compile:\n{}", compilation.diagnostics)
+            }
+            BlockFailure::Crashed(stderr) => write!(f, "compiled but failed when run:\n{}", stderr),
+            BlockFailure::NotRejected(failure) => failure.fmt(f),
+            BlockFailure::Rustc(err) => write!(f, "cannot run rustc: {}", err),
+        }
+    }
+}
+
+/// Checks one block according to its mode.
+pub fn verify(block: &CodeBlock) -> Result<(), BlockFailure> {
+    let name = format!("article_line_{}", block.line);
+    let program = block.program();
+    match &block.mode {
+        Mode::Ignore => Ok(()),
+        Mode::NoRun => {
+            let compilation = rustc::check(&name, &program).map_err(BlockFailure::Rustc)?;
+            if compilation.success {
+                Ok(())
+            } else {
+                Err(BlockFailure::DoesNotCompile(compilation))
+            }
+        }
+        Mode::Run => {
+            let execution = rustc::build_and_run(&name, &program).map_err(BlockFailure::Rustc)?;
+            match execution.output {
+                None => Err(BlockFailure::DoesNotCompile(execution.compilation)),
+                Some(output) if !output.status.success() => Err(BlockFailure::Crashed(
+                    String::from_utf8_lossy(&output.stderr).into_owned(),
+                )),
+                Some(_) => Ok(()),
+            }
+        }
+        Mode::ShouldFail(expected) => {
+            let case = Case {
+                name,
+                source: program,
+                expected: expected.clone(),
+            };
+            match compile_fail::check(&case) {
+                Ok(_) => Ok(()),
+                Err(compile_fail::Failure::Rustc(err)) => Err(BlockFailure::Rustc(err)),
+                Err(failure) => Err(BlockFailure::NotRejected(failure)),
+            }
+        }
+    }
+}
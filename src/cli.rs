@@ -0,0 +1,315 @@
+//! The command line of the lesson runner.
+//!
+//! ```text
+//! rust-live-6-ownership list
+//! rust-live-6-ownership run <id|number>
+//! rust-live-6-ownership run --all
+//! rust-live-6-ownership explain <id|number>
+//! rustc --error-format=json main.rs 2>&1 | rust-live-6-ownership diagnose
+//! rust-live-6-ownership verify-article [path]
+//! rust-live-6-ownership sync [--write] [path]
+//! ```
+//!
+//! Without arguments every lesson runs, as `run --all` does.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use crate::{article, explain, find, registry, sync, Lesson};
+
+/// The article `verify-article` and `sync` read when no path is given.
+pub const DEFAULT_ARTICLE: &str = "article.md";
+
+pub const USAGE: &str = "\
+usage: rust-live-6-ownership <command>
+
+commands:
+  list                  list the lessons
+  run <id|number>       run one lesson
+  run --all             run every lesson in order
+  explain <id|number>   print what a lesson teaches
+  diagnose [file]       explain the ownership errors in rustc's JSON output
+                        (read from the file, or from stdin)
+  verify-article [path] compile the Rust blocks of a markdown file as their
+                        annotations say
+  sync [path]           report where the article's code blocks differ from
+                        the lessons
+  sync --write [path]   rewrite the article's code blocks from the lessons
+
+verify-article and sync read ./article.md unless given a path.";
+
+/// A parsed command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    List,
+    Run(Selection),
+    Explain(String),
+    /// Explain rustc diagnostics read from a file, or from stdin if `None`.
+    Diagnose(Option<String>),
+    /// Check the code blocks of a markdown file, or of [`DEFAULT_ARTICLE`] if `None`.
+    VerifyArticle(Option<String>),
+    /// Compare the article ([`DEFAULT_ARTICLE`] if `path` is `None`) with the
+    /// lessons, or rewrite it if `write` is set.
+    Sync {
+        path: Option<String>,
+        write: bool,
+    },
+    Help,
+}
+
+/// Which lessons `run` should execute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    One(String),
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    /// The arguments do not form a command.
+    Usage(String),
+    /// No lesson has the given id or number.
+    UnknownSection(String),
+    /// Some checks failed; the details have already been written out.
+    Failed(usize),
+    Io(io::Error),
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CliError::UnknownSection(_) => 1,
+            CliError::Usage(_) => 2,
+            CliError::Failed(_) => 3,
+            CliError::Io(_) => 74,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            CliError::UnknownSection(key) => write!(
+                f,
+                "unknown section `{}` (try `list` to see the lessons)",
+                key
+            ),
+            CliError::Failed(count) => write!(f, "{} check(s) failed", count),
+            CliError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(err: io::Error) -> Self {
+        CliError::Io(err)
+    }
+}
+
+/// Parses the arguments that follow the program name.
+pub fn parse<I, S>(args: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let args: Vec<String> = args.into_iter().map(Into::into).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        [] => Ok(Command::Run(Selection::All)),
+        ["list"] => Ok(Command::List),
+        ["run", "--all"] => Ok(Command::Run(Selection::All)),
+        ["run", key] if !key.starts_with('-') => Ok(Command::Run(Selection::One(key.to_string()))),
+        ["explain", key] => Ok(Command::Explain(key.to_string())),
+        ["diagnose"] => Ok(Command::Diagnose(None)),
+        ["diagnose", path] => Ok(Command::Diagnose(Some(path.to_string()))),
+        ["verify-article"] => Ok(Command::VerifyArticle(None)),
+        ["verify-article", path] => Ok(Command::VerifyArticle(Some(path.to_string()))),
+        ["sync"] => Ok(Command::Sync {
+            path: None,
+            write: false,
+        }),
+        ["sync", "--write"] => Ok(Command::Sync {
+            path: None,
+            write: true,
+        }),
+        ["sync", "--write", path] => Ok(Command::Sync {
+            path: Some(path.to_string()),
+            write: true,
+        }),
+        ["sync", path] if !path.starts_with('-') => Ok(Command::Sync {
+            path: Some(path.to_string()),
+            write: false,
+        }),
+        ["help"] | ["-h"] | ["--help"] => Ok(Command::Help),
+        ["run"] => Err(CliError::Usage("`run` needs a lesson or `--all`".into())),
+        ["explain"] => Err(CliError::Usage("`explain` needs a lesson".into())),
+        [command
+        @ ("list" | "run" | "explain" | "diagnose" | "verify-article" | "sync" | "help"), rest @ ..] => {
+            Err(unexpected(command, rest))
+        }
+        [other, ..] => Err(CliError::Usage(format!("unknown command `{}`", other))),
+    }
+}
+
+/// The usage error for a known command given arguments it does not take:
+/// names an unknown flag if there is one, a known flag in the wrong place,
+/// else the first surplus argument.
+fn unexpected(command: &str, rest: &[&str]) -> CliError {
+    let (flags, takes): (&[&str], usize) = match command {
+        "run" => (&["--all"], 1),
+        "sync" => (&["--write"], 2),
+        "explain" | "diagnose" | "verify-article" => (&[], 1),
+        _ => (&[], 0),
+    };
+    let flag = rest.iter().position(|arg| flags.contains(arg));
+    let message = if let Some(unknown) = rest
+        .iter()
+        .find(|arg| arg.starts_with('-') && !flags.contains(arg))
+    {
+        format!("`{}` does not take `{}`", command, unknown)
+    } else if command == "run" && flag.is_some() {
+        "`run` takes a lesson or `--all`, not both".to_string()
+    } else if let Some(i) = flag.filter(|&i| i > 0) {
+        format!("`{}` must come before the path", rest[i])
+    } else {
+        let bad = rest.get(takes).or(rest.last()).copied().unwrap_or_default();
+        format!("`{}` does not take `{}`", command, bad)
+    };
+    CliError::Usage(message)
+}
+
+/// Executes a command, writing its output to `out`.
+pub fn execute(command: &Command, out: &mut impl Write) -> Result<(), CliError> {
+    match command {
+        Command::List => {
+            let width = registry().iter().map(|l| l.id.len()).max().unwrap_or(0);
+            for lesson in registry() {
+                writeln!(
+                    out,
+                    "{:>2}  {:<width$} {}",
+                    lesson.number,
+                    lesson.id,
+                    lesson.title,
+                    width = width
+                )?;
+            }
+        }
+        Command::Run(Selection::All) => {
+            for (i, lesson) in registry().iter().enumerate() {
+                if i > 0 {
+                    writeln!(out)?;
+                }
+                run_lesson(lesson, out)?;
+            }
+        }
+        Command::Run(Selection::One(key)) => run_lesson(lookup(key)?, out)?,
+        Command::Explain(key) => {
+            let lesson = lookup(key)?;
+            writeln!(out, "{} ({})", lesson.heading(), lesson.id)?;
+            writeln!(out)?;
+            writeln!(out, "{}", lesson.explanation)?;
+        }
+        Command::Diagnose(path) => {
+            let output = match path {
+                Some(path) => read_file(path)?,
+                None => io::read_to_string(io::stdin())?,
+            };
+            let explanations = explain::explain_output(&output);
+            if explanations.is_empty() {
+                writeln!(out, "no ownership errors found")?;
+            }
+            for (i, explanation) in explanations.iter().enumerate() {
+                if i > 0 {
+                    writeln!(out)?;
+                }
+                writeln!(out, "{}", explanation)?;
+            }
+        }
+        Command::VerifyArticle(path) => {
+            let text = read_file(path.as_deref().unwrap_or(DEFAULT_ARTICLE))?;
+            let mut failed = 0;
+            for block in article::code_blocks(&text) {
+                let label = format!("line {:<4} {} ({})", block.line, block.heading, block.mode);
+                match article::verify(&block) {
+                    Ok(()) => writeln!(out, "ok   {}", label)?,
+                    Err(failure) => {
+                        failed += 1;
+                        writeln!(out, "FAIL {}: {}", label, failure)?;
+                    }
+                }
+            }
+            if failed > 0 {
+                return Err(CliError::Failed(failed));
+            }
+        }
+        Command::Sync { path, write: true } => {
+            let path = path.as_deref().unwrap_or(DEFAULT_ARTICLE);
+            let text = read_file(path)?;
+            let rewritten = sync::rewrite(&text);
+            if rewritten == text {
+                writeln!(out, "{} is up to date", path)?;
+            } else {
+                std::fs::write(path, rewritten)?;
+                writeln!(out, "rewrote the code blocks of {}", path)?;
+            }
+        }
+        Command::Sync { path, write: false } => {
+            let text = read_file(path.as_deref().unwrap_or(DEFAULT_ARTICLE))?;
+            let differences = sync::check(&text);
+            for difference in &differences {
+                let tag = if difference.is_drift() {
+                    "DRIFT"
+                } else {
+                    "note "
+                };
+                writeln!(out, "{} {}", tag, difference)?;
+            }
+            let drifted = differences.iter().filter(|d| d.is_drift()).count();
+            if drifted > 0 {
+                return Err(CliError::Failed(drifted));
+            }
+            writeln!(out, "the article matches the lessons")?;
+        }
+        Command::Help => writeln!(out, "{}", USAGE)?,
+    }
+    Ok(())
+}
+
+/// Parses and executes the arguments, reporting errors on stderr.
+pub fn main<I, S>(args: I) -> ExitCode
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let result = parse(args).and_then(|command| execute(&command, &mut io::stdout().lock()));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // `run --all | head` closing the pipe early is not an error.
+        Err(CliError::Io(err)) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+fn lookup(key: &str) -> Result<&'static Lesson, CliError> {
+    find(key).ok_or_else(|| CliError::UnknownSection(key.to_string()))
+}
+
+/// Reads a file, naming it in the error if it cannot be read.
+fn read_file(path: &str) -> io::Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path, err)))
+}
+
+fn run_lesson(lesson: &Lesson, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{}", lesson.heading())?;
+    for (step, line) in lesson.run().lines().iter().enumerate() {
+        writeln!(out, "  {}.{} {}", lesson.number, step + 1, line)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//! Compile-fail cases: snippets that must be rejected by the compiler.
+//!
+//! A case is a Rust source file whose leading comment names the error codes
+//! rustc has to report for it:
+//!
+//! ```text
+//! // error: E0382
+//! // Section 4: s1 is used after its value moved into s2.
+//! fn main() { ... }
+//! ```
+//!
+//! [`check`] compiles a case with the local `rustc` (see [`crate::rustc`]) and
+//! compares the reported codes with the expected ones.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::rustc::{self, Compilation};
+
+/// One snippet that must not compile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub name: String,
+    pub source: String,
+    /// Error codes such as `E0382` that rustc must report.
+    pub expected: Vec<String>,
+}
+
+impl Case {
+    /// Builds a case from its source, reading the expected codes from the
+    /// `// error: E....` lines of its leading comment.
+    pub fn new(name: &str, source: &str) -> Case {
+        let expected = source
+            .lines()
+            .take_while(|line| line.trim_start().starts_with("//"))
+            .filter_map(|line| line.trim_start().strip_prefix("// error:"))
+            .flat_map(|codes| codes.split([',', ' ']))
+            .filter(|code| !code.is_empty())
+            .map(str::to_string)
+            .collect();
+        Case {
+            name: name.to_string(),
+            source: source.to_string(),
+            expected,
+        }
+    }
+
+    pub fn load(path: &Path) -> io::Result<Case> {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("case");
+        Ok(Case::new(name, &fs::read_to_string(path)?))
+    }
+}
+
+/// Loads every `.rs` file of `dir` as a case, sorted by name.
+pub fn load_dir(dir: &Path) -> io::Result<Vec<Case>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<_>>()?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "rs"));
+    paths.sort();
+    paths.iter().map(|path| Case::load(path)).collect()
+}
+
+/// Why a case did not behave as expected.
+#[derive(Debug)]
+pub enum Failure {
+    /// The snippet compiled.
+    Compiled,
+    /// rustc rejected the snippet, but without some of the expected codes.
+    MissingCodes {
+        missing: Vec<String>,
+        compilation: Compilation,
+    },
+    /// rustc could not be run.
+    Rustc(io::Error),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Compiled => write!(f, "compiled, but it should have been rejected"),
+            Failure::MissingCodes {
+                missing,
+                compilation,
+            } => write!(
+                f,
+                "expected {} but rustc reported [{}]:\n{}",
+                missing.join(", "),
+                compilation.codes.join(", "),
+                compilation.diagnostics
+            ),
+            Failure::Rustc(err) => write!(f, "cannot run rustc: {}", err),
+        }
+    }
+}
+
+/// Compiles a case and checks that rustc rejects it with the expected codes.
+pub fn check(case: &Case) -> Result<Compilation, Failure> {
+    let compilation = rustc::check(&case.name, &case.source).map_err(Failure::Rustc)?;
+    if compilation.success {
+        return Err(Failure::Compiled);
+    }
+    let missing: Vec<String> = case
+        .expected
+        .iter()
+        .filter(|code| !compilation.codes.contains(code))
+        .cloned()
+        .collect();
+    if missing.is_empty() {
+        Ok(compilation)
+    } else {
+        Err(Failure::MissingCodes {
+            missing,
+            compilation,
+        })
+    }
+}
@@ -0,0 +1,261 @@
+//! ASCII stack/heap diagrams of `String`s, like the figures the article
+//! refers to.
+//!
+//! Variables that point at the same buffer share one arrow:
+//!
+//! ```text
+//!            stack                                   heap
+//! s1 (moved) [ptr 0x5581a2c0 | len 5 | cap 5] --+--> 0x5581a2c0 [h|e|l|l|o]
+//! s2         [ptr 0x5581a2c0 | len 5 | cap 5] --+
+//! ```
+//!
+//! A [`Timeline`] draws scope lines instead: which source lines each owner and
+//! each borrow is alive on.
+//!
+//! ```text
+//! line    1 2 3 4 5 6
+//! string1 ============ owner
+//! string2   ======     owner
+//! result      ----!!!! borrows string2, dropped after line 4
+//! ```
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::inspect::StringLayout;
+
+/// A diagram of some `String` variables and the buffers they point to.
+#[derive(Debug, Clone, Default)]
+pub struct Diagram {
+    rows: Vec<Row>,
+}
+
+#[derive(Debug, Clone)]
+struct Row {
+    name: String,
+    layout: StringLayout,
+    moved: bool,
+}
+
+impl Diagram {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variable that still owns its value.
+    pub fn string(mut self, name: &str, layout: StringLayout) -> Self {
+        self.push(name, layout, false);
+        self
+    }
+
+    /// Adds a variable as it was before its value was moved out of it.
+    pub fn moved(mut self, name: &str, layout: StringLayout) -> Self {
+        self.push(name, layout, true);
+        self
+    }
+
+    fn push(&mut self, name: &str, layout: StringLayout, moved: bool) {
+        self.rows.push(Row {
+            name: name.to_string(),
+            layout,
+            moved,
+        });
+    }
+
+    /// The rows, grouped so that variables sharing a buffer are adjacent.
+    fn groups(&self) -> Vec<Vec<&Row>> {
+        let mut groups: Vec<Vec<&Row>> = Vec::new();
+        for row in &self.rows {
+            match groups
+                .iter_mut()
+                .find(|group| group[0].layout.shares_buffer_with(&row.layout))
+            {
+                Some(group) => group.push(row),
+                None => groups.push(vec![row]),
+            }
+        }
+        groups
+    }
+}
+
+impl fmt::Display for Diagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                if row.moved {
+                    format!("{} (moved)", row.name)
+                } else {
+                    row.name.clone()
+                }
+            })
+            .collect();
+        let width = names.iter().map(String::len).max().unwrap_or(0);
+
+        let mut lines = Vec::new();
+        for group in self.groups() {
+            for (i, row) in group.iter().enumerate() {
+                let index = self
+                    .rows
+                    .iter()
+                    .position(|r| std::ptr::eq(r, *row))
+                    .expect("row belongs to the diagram");
+                let layout = &row.layout;
+                let stack = format!(
+                    "{:<width$} [ptr {:#x} | len {} | cap {}] --",
+                    names[index],
+                    layout.heap_ptr,
+                    layout.len,
+                    layout.capacity,
+                    width = width
+                );
+                lines.push(match (i, group.len()) {
+                    (0, 1) => format!("{}---> {}", stack, heap(layout)),
+                    (0, _) => format!("{}+--> {}", stack, heap(layout)),
+                    _ => format!("{}+", stack),
+                });
+            }
+        }
+
+        // Label the columns over the first row's stack triple and buffer.
+        if let Some(first) = lines.first() {
+            let heap_column = first.find("> ").map_or(0, |i| i + 2);
+            let stack_column = width + 1;
+            write!(
+                f,
+                "{:stack_column$}stack{:gap$}heap",
+                "",
+                "",
+                stack_column = stack_column,
+                gap = heap_column.saturating_sub(stack_column + "stack".len()),
+            )?;
+        }
+        for line in &lines {
+            write!(f, "\n{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+fn heap(layout: &StringLayout) -> String {
+    let cells: Vec<String> = layout
+        .bytes
+        .iter()
+        .map(|&byte| match byte {
+            0x20..=0x7e => (byte as char).to_string(),
+            _ => format!("{:02x}", byte),
+        })
+        .collect();
+    format!("{:#x} [{}]", layout.heap_ptr, cells.join("|"))
+}
+
+/// Scope lines of owners and the borrows of them, one column per source line.
+#[derive(Debug, Clone, Default)]
+pub struct Timeline {
+    rows: Vec<Lifeline>,
+}
+
+#[derive(Debug, Clone)]
+struct Lifeline {
+    name: String,
+    lines: RangeInclusive<usize>,
+    /// The owner a borrow refers to; `None` for owners.
+    of: Option<String>,
+}
+
+impl Timeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a variable that owns its value from the first line to the last.
+    pub fn owner(mut self, name: &str, lines: RangeInclusive<usize>) -> Self {
+        self.rows.push(Lifeline {
+            name: name.to_string(),
+            lines,
+            of: None,
+        });
+        self
+    }
+
+    /// Adds a reference to `owner`'s value, used from the first line to the
+    /// last.
+    pub fn borrow(mut self, name: &str, owner: &str, lines: RangeInclusive<usize>) -> Self {
+        self.rows.push(Lifeline {
+            name: name.to_string(),
+            lines,
+            of: Some(owner.to_string()),
+        });
+        self
+    }
+
+    /// The borrows still in use after their owner is gone, with the last line
+    /// the owner was alive on: what rustc reports as E0597.
+    pub fn dangling(&self) -> Vec<(&str, usize)> {
+        self.rows
+            .iter()
+            .filter_map(|row| {
+                let end = self.owner_end(row)?;
+                (*row.lines.end() > end).then_some((row.name.as_str(), end))
+            })
+            .collect()
+    }
+
+    /// The last line of the owner `row` borrows from, if it is a borrow.
+    fn owner_end(&self, row: &Lifeline) -> Option<usize> {
+        let of = row.of.as_deref()?;
+        self.rows
+            .iter()
+            .find(|owner| owner.of.is_none() && owner.name == of)
+            .map(|owner| *owner.lines.end())
+    }
+}
+
+impl fmt::Display for Timeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let first = self.rows.iter().map(|row| *row.lines.start()).min();
+        let last = self.rows.iter().map(|row| *row.lines.end()).max();
+        let (Some(first), Some(last)) = (first, last) else {
+            return Ok(());
+        };
+        let width = self
+            .rows
+            .iter()
+            .map(|row| row.name.len())
+            .chain(["line".len()])
+            .max()
+            .unwrap_or(0);
+
+        // Two characters per line; numbers past 9 show their last digit.
+        let numbers: Vec<String> = (first..=last).map(|n| (n % 10).to_string()).collect();
+        write!(f, "{:<width$} {}", "line", numbers.join(" "), width = width)?;
+        for row in &self.rows {
+            let owner_end = self.owner_end(row);
+            let cells: String = (first..=last)
+                .map(|line| match (row.lines.contains(&line), row.of.is_some()) {
+                    (false, _) => "  ",
+                    (true, false) => "==",
+                    (true, true) if owner_end.is_some_and(|end| line > end) => "!!",
+                    (true, true) => "--",
+                })
+                .collect();
+            let note = match (&row.of, owner_end) {
+                (None, _) => "owner".to_string(),
+                (Some(of), Some(end)) if *row.lines.end() > end => {
+                    format!("borrows {}, dropped after line {}", of, end)
+                }
+                (Some(of), _) => format!("borrows {}", of),
+            };
+            write!(
+                f,
+                "\n{:<width$} {} {}",
+                row.name,
+                cells,
+                note,
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
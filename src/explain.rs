@@ -0,0 +1,227 @@
+//! Maps rustc's ownership errors back to the lessons.
+//!
+//! Feed it the output of `rustc --error-format=json` (or
+//! `cargo build --message-format=json`) and every ownership-related error
+//! comes back with the lesson that covers it, the passage of `article.md`
+//! that explains it and the usual fix.
+
+use std::fmt;
+
+use crate::json::{self, Value};
+use crate::{article, find, Lesson};
+
+/// One error or warning reported by rustc.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub level: String,
+    pub code: Option<String>,
+    pub message: String,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+}
+
+impl Diagnostic {
+    /// Reads one rustc JSON diagnostic. Cargo wraps diagnostics in a
+    /// `compiler-message`; both shapes are accepted.
+    pub fn from_json(value: &Value) -> Option<Diagnostic> {
+        let value = value
+            .get("message")
+            .filter(|m| m.get("level").is_some())
+            .unwrap_or(value);
+        let primary = value
+            .get("spans")
+            .map(Value::as_array)
+            .unwrap_or_default()
+            .iter()
+            .find(|span| span.get("is_primary").and_then(Value::as_bool) == Some(true));
+        Some(Diagnostic {
+            level: value.get("level")?.as_str()?.to_string(),
+            code: value
+                .get("code")
+                .and_then(|code| code.get("code"))
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            message: value.get("message")?.as_str()?.to_string(),
+            file: primary
+                .and_then(|span| span.get("file_name"))
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            line: primary
+                .and_then(|span| span.get("line_start"))
+                .and_then(Value::as_f64)
+                .map(|line| line as usize),
+        })
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.level)?;
+        if let Some(code) = &self.code {
+            write!(f, "[{}]", code)?;
+        }
+        write!(f, ": {}", self.message)?;
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => write!(f, " ({}:{})", file, line),
+            (None, Some(line)) => write!(f, " (line {})", line),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Reads the diagnostics out of rustc's JSON output, one object per line.
+/// Lines that are not JSON diagnostics, such as cargo's progress output, are skipped.
+pub fn parse_diagnostics(output: &str) -> Vec<Diagnostic> {
+    output
+        .lines()
+        .filter(|line| line.trim_start().starts_with('{'))
+        .filter_map(|line| json::parse(line).ok())
+        .filter_map(|value| Diagnostic::from_json(&value))
+        .collect()
+}
+
+/// An ownership error code and what the course has to say about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub code: &'static str,
+    /// Id of the lesson that covers the error.
+    pub lesson: &'static str,
+    /// A phrase from the paragraph of `article.md` that explains the error.
+    pub article: &'static str,
+    pub fix: &'static str,
+}
+
+/// The ownership errors the course covers.
+pub const RULES: &[Rule] = &[
+    Rule {
+        code: "E0382",
+        lesson: "move",
+        article: "assigning a value to another variable moves it",
+        fix: "clone the value before moving it if both variables need it, as section 5 \
+does with `let s2 = s1.clone();`",
+    },
+    Rule {
+        code: "E0505",
+        lesson: "move",
+        article: "assigning a value to another variable moves it",
+        fix: "finish using the borrow before moving the value, or move a `.clone()` instead",
+    },
+    Rule {
+        code: "E0507",
+        lesson: "partial-moves",
+        article: "If we do want to deeply copy the heap data",
+        fix: "you cannot move out of something you only borrow or index; take a \
+`.clone()` of it, or swap a value in with `std::mem::take`, `std::mem::replace` or \
+`Option::take`",
+    },
+    Rule {
+        code: "E0499",
+        lesson: "references",
+        article: "which allow you to refer to some value without taking ownership",
+        fix: "end the first mutable borrow before taking the second, for example by \
+giving it its own `{ }` scope",
+    },
+    Rule {
+        code: "E0502",
+        lesson: "references",
+        article: "which allow you to refer to some value without taking ownership",
+        fix: "finish using the shared references before borrowing mutably; a value \
+has either one `&mut` or any number of `&` at a time",
+    },
+    Rule {
+        code: "E0373",
+        lesson: "threads",
+        article: "assigning a value to another variable moves it",
+        fix: "add `move` so the closure owns what it captures; clone first if the \
+spawning thread still needs the value",
+    },
+    Rule {
+        code: "E0597",
+        lesson: "lifetimes",
+        article: "When `s` goes out of scope, the string will be dropped",
+        fix: "declare the value in an outer scope so it lives as long as its borrow",
+    },
+    Rule {
+        code: "E0106",
+        lesson: "lifetimes",
+        article: "refer to some value without taking ownership of it",
+        fix:
+            "add a lifetime parameter, as in `fn longest<'a>(x: &'a str, y: &'a str) -> &'a str`, \
+or return an owned String",
+    },
+    Rule {
+        code: "E0384",
+        lesson: "string-type",
+        article: "The `String` type, however, is mutable",
+        fix: "declare the variable with `let mut`, as section 2 does with `let mut s`",
+    },
+    Rule {
+        code: "E0596",
+        lesson: "string-type",
+        article: "The `String` type, however, is mutable",
+        fix: "declare the variable with `let mut`, as section 2 does with `let mut s`",
+    },
+    Rule {
+        code: "E0204",
+        lesson: "copy-types",
+        article: "any group of simple scalar values can implement Copy",
+        fix: "only derive `Copy` when every field is `Copy`; otherwise derive `Clone` \
+and call `.clone()`",
+    },
+    Rule {
+        code: "E0184",
+        lesson: "copy-types",
+        article: "Rust won’t let us annotate a type with Copy",
+        fix: "a type with a `Drop` implementation cannot be `Copy`; drop one of the two",
+    },
+];
+
+/// A diagnostic matched with the lesson that covers it.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    pub diagnostic: Diagnostic,
+    pub lesson: &'static Lesson,
+    /// The paragraph of `article.md` that explains the error.
+    pub article: Option<String>,
+    pub fix: &'static str,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.diagnostic)?;
+        write!(
+            f,
+            "  lesson:  {} (run `explain {}`)",
+            self.lesson.heading(),
+            self.lesson.id
+        )?;
+        if let Some(article) = &self.article {
+            write!(f, "\n  article: {}", article)?;
+        }
+        write!(f, "\n  fix:     {}", self.fix)
+    }
+}
+
+/// The rule for an error code, if it is an ownership error the course covers.
+pub fn rule(code: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|rule| rule.code == code)
+}
+
+/// Explains a diagnostic, or returns `None` if it is not an ownership error.
+pub fn explain(diagnostic: &Diagnostic) -> Option<Explanation> {
+    let rule = rule(diagnostic.code.as_deref()?)?;
+    Some(Explanation {
+        diagnostic: diagnostic.clone(),
+        lesson: find(rule.lesson)?,
+        article: article::paragraph_containing(article::TEXT, rule.article),
+        fix: rule.fix,
+    })
+}
+
+/// Explains every ownership error in rustc's JSON output.
+pub fn explain_output(output: &str) -> Vec<Explanation> {
+    parse_diagnostics(output)
+        .iter()
+        .filter_map(explain)
+        .collect()
+}
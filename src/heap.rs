@@ -0,0 +1,186 @@
+//! Heap allocation counting.
+//!
+//! With the `count-alloc` feature the crate installs a counting allocator
+//! as the global allocator, and [`measure`] reports how many allocations a
+//! piece of code made on the current thread. Without the feature `measure`
+//! still runs the code but has nothing to report.
+//!
+//! ```
+//! use rust_live_6_ownership::heap;
+//!
+//! let (s, stats) = heap::measure(|| String::from("hello"));
+//! if let Some(stats) = stats {
+//!     assert_eq!(stats.allocations, 1);
+//! }
+//! # drop(s);
+//! ```
+
+use std::fmt;
+use std::ops::Sub;
+
+/// Whether allocations are being counted in this build.
+pub const ENABLED: bool = cfg!(feature = "count-alloc");
+
+/// Allocator activity over some stretch of code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    pub allocations: usize,
+    pub deallocations: usize,
+    /// Blocks grown or shrunk in place of a fresh allocation, as `push_str` does.
+    pub reallocations: usize,
+    pub bytes_allocated: usize,
+    pub bytes_freed: usize,
+}
+
+#[cfg(feature = "count-alloc")]
+impl HeapStats {
+    const ZERO: HeapStats = HeapStats {
+        allocations: 0,
+        deallocations: 0,
+        reallocations: 0,
+        bytes_allocated: 0,
+        bytes_freed: 0,
+    };
+}
+
+impl Sub for HeapStats {
+    type Output = HeapStats;
+
+    fn sub(self, earlier: HeapStats) -> HeapStats {
+        HeapStats {
+            allocations: self.allocations - earlier.allocations,
+            deallocations: self.deallocations - earlier.deallocations,
+            reallocations: self.reallocations - earlier.reallocations,
+            bytes_allocated: self.bytes_allocated - earlier.bytes_allocated,
+            bytes_freed: self.bytes_freed - earlier.bytes_freed,
+        }
+    }
+}
+
+impl fmt::Display for HeapStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocation{}, {} deallocation{}",
+            self.allocations,
+            plural(self.allocations),
+            self.deallocations,
+            plural(self.deallocations)
+        )?;
+        if self.reallocations > 0 {
+            write!(
+                f,
+                ", {} reallocation{}",
+                self.reallocations,
+                plural(self.reallocations)
+            )?;
+        }
+        write!(
+            f,
+            ", {} bytes allocated, {} bytes freed",
+            self.bytes_allocated, self.bytes_freed
+        )
+    }
+}
+
+fn plural(n: usize) -> &'static str {
+    if n == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+/// Runs `f` and returns its result together with the allocations it made
+/// on this thread, or `None` when counting is not enabled.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<HeapStats>) {
+    let before = counts::snapshot();
+    let result = f();
+    let after = counts::snapshot();
+    (
+        result,
+        after.zip(before).map(|(after, before)| after - before),
+    )
+}
+
+#[cfg(feature = "count-alloc")]
+pub use counts::CountingAllocator;
+
+#[cfg(feature = "count-alloc")]
+mod counts {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::HeapStats;
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    thread_local! {
+        // Per thread, so that tests running in parallel do not see each
+        // other's allocations. `const` keeps the allocator from allocating
+        // to initialise its own counters.
+        static COUNTS: Cell<HeapStats> = const { Cell::new(HeapStats::ZERO) };
+    }
+
+    /// The system allocator, counting what it does on each thread.
+    pub struct CountingAllocator;
+
+    fn update(f: impl FnOnce(&mut HeapStats)) {
+        // The thread-local is gone while a thread shuts down; allocations
+        // made that late are simply not counted.
+        let _ = COUNTS.try_with(|counts| {
+            let mut stats = counts.get();
+            f(&mut stats);
+            counts.set(stats);
+        });
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            update(|stats| {
+                stats.allocations += 1;
+                stats.bytes_allocated += layout.size();
+            });
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            update(|stats| {
+                stats.allocations += 1;
+                stats.bytes_allocated += layout.size();
+            });
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            update(|stats| {
+                stats.deallocations += 1;
+                stats.bytes_freed += layout.size();
+            });
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            update(|stats| {
+                stats.reallocations += 1;
+                stats.bytes_allocated += new_size;
+                stats.bytes_freed += layout.size();
+            });
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    pub fn snapshot() -> Option<HeapStats> {
+        COUNTS.try_with(Cell::get).ok()
+    }
+}
+
+#[cfg(not(feature = "count-alloc"))]
+mod counts {
+    use super::HeapStats;
+
+    pub fn snapshot() -> Option<HeapStats> {
+        None
+    }
+}
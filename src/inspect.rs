@@ -0,0 +1,107 @@
+//! A look inside a `String`: the pointer, length and capacity on the stack
+//! and the bytes they point to on the heap.
+//!
+//! ```
+//! use rust_live_6_ownership::inspect::{inspect, BufferChange};
+//!
+//! let mut s = String::with_capacity(5);
+//! s.push_str("hello");
+//! let before = inspect(&s);
+//! assert_eq!((before.len, before.capacity), (5, 5));
+//!
+//! s.push_str(", world!");
+//! let after = inspect(&s);
+//! assert_ne!(BufferChange::between(&before, &after), BufferChange::Unchanged);
+//! ```
+
+use std::fmt;
+
+/// The three stack words of a `String` and the heap bytes they describe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringLayout {
+    /// Where the `String` itself (pointer, length, capacity) lives.
+    pub stack_addr: usize,
+    /// Where its buffer starts.
+    pub heap_ptr: usize,
+    pub len: usize,
+    pub capacity: usize,
+    /// The first `len` bytes of the buffer.
+    pub bytes: Vec<u8>,
+}
+
+impl StringLayout {
+    /// Whether both strings point at the same heap buffer.
+    pub fn shares_buffer_with(&self, other: &StringLayout) -> bool {
+        self.capacity > 0 && self.heap_ptr == other.heap_ptr
+    }
+
+    /// Where `slice` starts inside this string's bytes, or `None` if it
+    /// points somewhere else.
+    pub fn offset_of(&self, slice: &str) -> Option<usize> {
+        let start = slice.as_ptr() as usize;
+        let offset = start.checked_sub(self.heap_ptr)?;
+        (offset + slice.len() <= self.len).then_some(offset)
+    }
+}
+
+impl fmt::Display for StringLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "stack {:#x}: ptr {:#x}, len {}, capacity {}, bytes [",
+            self.stack_addr, self.heap_ptr, self.len, self.capacity
+        )?;
+        for (i, byte) in self.bytes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Takes a `&String` rather than a `&str` on purpose: the stack address and
+/// capacity belong to the `String`, not to the text it holds.
+#[allow(clippy::ptr_arg)]
+pub fn inspect(s: &String) -> StringLayout {
+    StringLayout {
+        stack_addr: s as *const String as usize,
+        heap_ptr: s.as_ptr() as usize,
+        len: s.len(),
+        capacity: s.capacity(),
+        bytes: s.as_bytes().to_vec(),
+    }
+}
+
+/// What happened to a buffer between two inspections of the same `String`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferChange {
+    Unchanged,
+    /// The capacity changed but the allocator kept the buffer where it was.
+    GrownInPlace,
+    /// The bytes were copied to a new buffer and the old one freed.
+    Reallocated,
+}
+
+impl BufferChange {
+    pub fn between(before: &StringLayout, after: &StringLayout) -> BufferChange {
+        if before.heap_ptr != after.heap_ptr {
+            BufferChange::Reallocated
+        } else if before.capacity != after.capacity {
+            BufferChange::GrownInPlace
+        } else {
+            BufferChange::Unchanged
+        }
+    }
+}
+
+impl fmt::Display for BufferChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BufferChange::Unchanged => "the buffer did not change",
+            BufferChange::GrownInPlace => "the buffer grew in place",
+            BufferChange::Reallocated => "the buffer was reallocated",
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use crate::heap::HeapStats;
+use crate::trace::Tracer;
+
+/// One numbered section of the ownership walkthrough.
+#[derive(Debug, Clone, Copy)]
+pub struct Lesson {
+    /// Position in the walkthrough, starting at 1.
+    pub number: usize,
+    /// Short, stable name used to look the lesson up.
+    pub id: &'static str,
+    pub title: &'static str,
+    /// What the section teaches, in the words of the article.
+    pub explanation: &'static str,
+    /// The code of the section. It writes what it observes to the transcript.
+    pub body: fn(&mut Transcript),
+    /// The code blocks `article.md` shows for this section.
+    pub snippets: &'static [Snippet],
+}
+
+/// A code block of the article, owned by the lesson that teaches it.
+///
+/// The lessons are the source of truth: [`crate::sync`] checks the article
+/// against these snippets and can rewrite its code blocks from them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snippet {
+    /// The article heading the block appears under.
+    pub heading: &'static str,
+    /// The fence info string, e.g. `rust` or `rust,should_fail,E0382`.
+    pub info: &'static str,
+    pub code: &'static str,
+}
+
+impl Lesson {
+    /// Runs the body and returns everything it wrote.
+    pub fn run(&self) -> Transcript {
+        let mut transcript = Transcript::default();
+        (self.body)(&mut transcript);
+        transcript
+    }
+
+    /// The title as it appears in the walkthrough, e.g. `4. Ways Variables and Data Interact: Move`.
+    pub fn heading(&self) -> String {
+        format!("{}. {}", self.number, self.title)
+    }
+}
+
+/// The output of a lesson run, one line per entry.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    lines: Vec<String>,
+}
+
+impl Transcript {
+    /// Records one line of output.
+    pub fn say(&mut self, line: impl Into<String>) {
+        self.lines.push(line.into());
+    }
+
+    /// Records multi-line output, such as a diagram, one line per entry.
+    pub fn block(&mut self, text: impl std::fmt::Display) {
+        for line in text.to_string().lines() {
+            self.say(line);
+        }
+    }
+
+    /// Records the events `tracer` logged since the last call, one line each.
+    pub fn trace(&mut self, tracer: &Tracer) {
+        for event in tracer.drain() {
+            self.say(format!("[trace] {}", event));
+        }
+    }
+
+    /// Records the heap activity measured by [`crate::heap::measure`], if
+    /// allocations are being counted.
+    pub fn heap(&mut self, stats: Option<HeapStats>) {
+        if let Some(stats) = stats {
+            self.say(format!("[heap] {}", stats));
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+}
@@ -0,0 +1,76 @@
+use std::hint::black_box;
+
+use crate::trace::{Traced, Tracer};
+use crate::{heap, Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 18,
+    id: "box",
+    title: "Box and Recursive Data",
+    explanation: "Strings are not the only things on the heap. `Box<T>` puts any \
+value there and is its single owner: moving a Box copies only the pointer, and \
+dropping it frees the allocation. A recursive type such as a linked list needs a \
+Box to have a known size, and dropping the head drops the whole chain, one node \
+after the other.",
+    body: run,
+    snippets: &[],
+};
+
+/// A singly linked list: each node owns the rest of the list through a Box.
+pub enum List<T> {
+    Cons(T, Box<List<T>>),
+    Nil,
+}
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let b = Box::new(tracer.track("b", 5));
+        let before = &*b as *const Traced<i32> as usize;
+        let b2 = b; // moves the Box: the pointer is copied, the i32 stays put
+        let after = &*b2 as *const Traced<i32> as usize;
+        out.say(format!(
+            "Box<i32> at {:#x} before the move, {:#x} after: same allocation {}",
+            before,
+            after,
+            before == after
+        ));
+    }
+    out.trace(&tracer);
+
+    let list = cons(
+        tracer.track("node 1", 1),
+        cons(
+            tracer.track("node 2", 2),
+            cons(tracer.track("node 3", 3), List::Nil),
+        ),
+    );
+    out.trace(&tracer);
+    out.say(format!("list length {}", len(&list)));
+    drop(list); // node 1's drop drops its Box, which drops node 2, ...
+    out.trace(&tracer);
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+fn cons<T>(value: T, rest: List<T>) -> List<T> {
+    List::Cons(value, Box::new(rest))
+}
+
+/// The number of nodes, following the boxes.
+pub fn len<T>(list: &List<T>) -> usize {
+    match list {
+        List::Cons(_, rest) => 1 + len(rest),
+        List::Nil => 0,
+    }
+}
+
+/// The section as plain code: one allocation per Box, all freed together.
+pub fn example() {
+    let b = Box::new(5);
+    black_box(&b);
+
+    let list = cons(1, cons(2, cons(3, List::Nil)));
+    black_box(&list);
+}
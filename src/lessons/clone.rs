@@ -0,0 +1,54 @@
+use std::hint::black_box;
+
+use crate::diagram::Diagram;
+use crate::inspect::inspect;
+use crate::trace::Tracer;
+use crate::{heap, Lesson, Snippet, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 5,
+    id: "clone",
+    title: "Ways Variables and Data Interact: Clone",
+    explanation: "To deeply copy the heap data of a String, not just the stack data, \
+call `clone`. Both variables stay valid and each owns its own copy.",
+    body: run,
+    snippets: &[Snippet {
+        heading: "Variables and Data Interacting with Clone",
+        info: "rust",
+        code: r#"let s1 = String::from("hello");
+let s2 = s1.clone();
+
+println!("s1 = {}, s2 = {}", s1, s2);
+"#,
+    }],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let s1 = tracer.track("s1", String::from("hello"));
+        let s2 = s1.clone_as("s2");
+
+        out.say(format!("s1 = {}, s2 = {}", s1, s2));
+        let (layout1, layout2) = (inspect(&s1), inspect(&s2));
+        out.say(format!(
+            "same heap buffer after the clone: {}",
+            layout1.shares_buffer_with(&layout2)
+        ));
+        out.block(Diagram::new().string("s1", layout1).string("s2", layout2));
+    }
+
+    // Two Strings, two drops, in reverse order of declaration.
+    out.trace(&tracer);
+    out.say(format!("frees: {}", tracer.dropped().len()));
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let s1 = String::from("hello");
+    let s2 = s1.clone(); // a second buffer with its own copy of the bytes
+    black_box((&s1, &s2));
+}
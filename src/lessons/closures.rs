@@ -0,0 +1,80 @@
+use crate::trace::Tracer;
+use crate::{Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 14,
+    id: "closures",
+    title: "Closures and Captured Ownership",
+    explanation: "A closure captures each variable in the least demanding way its \
+body allows: by shared reference (Fn), by mutable reference (FnMut), or by value \
+when the body moves it (FnOnce, callable only once, so a second call is E0382). The \
+`move` keyword makes a closure take ownership of everything it captures even if its \
+body only reads it; the captured values are then dropped with the closure.",
+    body: run,
+    snippets: &[],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+
+    // Fn: the body only reads `greeting`, so the closure borrows it.
+    let greeting = tracer.track("greeting", String::from("hello"));
+    out.trace(&tracer);
+    let length = || greeting.len();
+    out.say(format!(
+        "Fn: called twice -> {} {}; greeting = {} is still usable",
+        call_fn(&length),
+        call_fn(&length),
+        greeting
+    ));
+
+    // FnMut: the body changes `log`, so the closure borrows it mutably.
+    // While `append` is alive, nothing else may touch log.
+    let mut log = tracer.track("log", String::new());
+    out.trace(&tracer);
+    let mut append = |word: &str| log.push_str(word);
+    call_fn_mut(&mut append, "a");
+    call_fn_mut(&mut append, "b");
+    out.say(format!("FnMut: log = {:?} once append is done", *log));
+
+    // FnOnce: the body moves `s`, so the closure owns it and can run once.
+    let s = tracer.track("s", String::from("consumed"));
+    out.trace(&tracer);
+    let consume = || {
+        let owned = s.moved_to("owned");
+        owned.len()
+    };
+    out.say(format!("FnOnce: returned {}", call_fn_once(consume)));
+    // call_fn_once(consume); // error[E0382]: use of moved value: `consume`
+    out.trace(&tracer); // owned was dropped inside the call
+
+    // move: the body only reads `data`, but the closure owns it anyway,
+    // so data is dropped with the closure, not at the end of the scope.
+    let data = tracer.track("data", String::from("owned by the closure"));
+    out.trace(&tracer);
+    let read = move || data.len();
+    out.say(format!("move: returned {}", call_fn(&read)));
+    // println!("{}", data); // error[E0382]: borrow of moved value: `data`
+    out.say("dropping the move closure");
+    drop(read);
+    out.trace(&tracer);
+
+    drop(greeting);
+    drop(log);
+    out.trace(&tracer);
+}
+
+/// Accepts any closure that can be called through a shared reference.
+pub fn call_fn<R>(f: &impl Fn() -> R) -> R {
+    f()
+}
+
+/// Accepts closures that need `&mut` access to what they captured.
+pub fn call_fn_mut(f: &mut impl FnMut(&str), arg: &str) {
+    f(arg)
+}
+
+/// Accepts closures that may consume what they captured; takes `f` by value.
+pub fn call_fn_once<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
@@ -0,0 +1,40 @@
+use std::hint::black_box;
+
+use crate::{heap, Lesson, Snippet, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 6,
+    id: "copy",
+    title: "Ways Variables and Data Interact: Copy",
+    explanation: "Integers have a known, fixed size and live entirely on the stack, \
+so `let y = x` copies the bits and both stay valid. Types like this implement the \
+`Copy` trait.",
+    body: run,
+    snippets: &[Snippet {
+        heading: "Stack-Only Data: Copy",
+        info: "rust",
+        code: r#"let x = 5;
+let y = x;
+
+println!("x = {}, y = {}", x, y);
+"#,
+    }],
+};
+
+fn run(out: &mut Transcript) {
+    let x = 5;
+    let y = x;
+
+    out.say(format!("x = {}, y = {}", x, y));
+    out.say("nothing to trace: i32 is Copy, so it has no destructor to run");
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let x = 5;
+    let y = x; // copies the bits on the stack
+    black_box((x, y));
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::trace::Tracer;
+use crate::{Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 11,
+    id: "copy-types",
+    title: "Which Types Are Copy",
+    explanation: "Any group of simple scalar values can implement Copy: integers, \
+bool, floating-point numbers, char, and tuples or structs made only of Copy types. \
+Nothing that requires allocation or is some form of resource can: `(i32, String)` is \
+not Copy, deriving Copy on a struct with a String field is error E0204, and a type \
+that implements Drop cannot be Copy (E0184).",
+    body: run,
+    snippets: &[],
+};
+
+/// A struct of Copy fields can derive `Copy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A String field rules `Copy` out: `#[derive(Copy)]` here is error E0204.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Labeled {
+    pub name: String,
+    pub value: i32,
+}
+
+/// Implements `Drop`, so it can never be `Copy` (E0184), even with no fields.
+#[derive(Debug)]
+pub struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+/// Whether a type implements `Copy`, decided at compile time.
+///
+/// ```
+/// use rust_live_6_ownership::is_copy;
+///
+/// assert!(is_copy!((i32, i32)));
+/// assert!(!is_copy!((i32, String)));
+/// ```
+#[macro_export]
+macro_rules! is_copy {
+    ($t:ty) => {{
+        #[allow(unused_imports)]
+        use $crate::lessons::copy_types::NotCopy as _;
+        $crate::lessons::copy_types::Probe::<$t>::new().is_copy()
+    }};
+}
+
+/// Used by [`is_copy!`]: the inherent `is_copy` only exists for `Copy`
+/// types and wins over the [`NotCopy`] fallback when it applies.
+#[doc(hidden)]
+pub struct Probe<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> Probe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Probe(PhantomData)
+    }
+}
+
+impl<T: Copy> Probe<T> {
+    pub fn is_copy(&self) -> bool {
+        true
+    }
+}
+
+#[doc(hidden)]
+pub trait NotCopy {
+    fn is_copy(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> NotCopy for Probe<T> {}
+
+struct Row {
+    name: &'static str,
+    copy: bool,
+    why: &'static str,
+}
+
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.copy { "Copy" } else { "not Copy" };
+        write!(f, "{:<16} {:<9} {}", self.name, verdict, self.why)
+    }
+}
+
+fn run(out: &mut Transcript) {
+    // The type's name and its verdict both come from the type itself.
+    macro_rules! row {
+        ($t:ty, $why:expr) => {
+            Row {
+                name: stringify!($t),
+                copy: is_copy!($t),
+                why: $why,
+            }
+        };
+    }
+
+    let rows = [
+        row!(u32, "integer"),
+        row!(bool, "true or false"),
+        row!(f64, "floating point"),
+        row!(char, "a single Unicode scalar value"),
+        row!((i32, i32), "a tuple of Copy types"),
+        row!((i32, String), "String owns a heap buffer"),
+        row!(&str, "shared references are Copy"),
+        row!(Point, "derives Copy; all fields are i32"),
+        row!(Labeled, "has a String field (E0204 if derived)"),
+        row!(Guard, "implements Drop (E0184 if derived)"),
+    ];
+    for row in &rows {
+        out.say(row.to_string());
+    }
+
+    let p1 = Point { x: 1, y: 2 };
+    let p2 = p1; // a copy: p1 stays valid
+    out.say(format!("p1 = {:?}, p2 = {:?}", p1, p2));
+
+    // The same assignment moves a tuple that holds a String.
+    let tracer = Tracer::new();
+    {
+        let t1 = (5, tracer.track("t1.1", String::from("hello")));
+        let t2 = t1; // t1 is moved; using it now is E0382
+        out.say(format!("t2 = ({}, {})", t2.0, t2.1));
+    }
+    out.trace(&tracer);
+}
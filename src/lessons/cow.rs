@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+use std::hint::black_box;
+
+use crate::{heap, Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 19,
+    id: "cow",
+    title: "Clone on Write with Cow",
+    explanation: "Moving a String and cloning it are not the only choices. \
+`Cow<'_, str>` is either a borrowed `&str` or an owned `String`: a function can \
+hand back its input untouched, and only allocate a new String for the inputs it \
+actually has to change.",
+    body: run,
+    snippets: &[],
+};
+
+/// Lines to clean up; only one of them contains a tab.
+pub const INPUTS: [&str; 5] = ["hello", "world", "tab\tseparated", "ownership", "rules"];
+
+/// Replaces tabs with spaces, borrowing the input when there are none.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    if input.contains('\t') {
+        Cow::Owned(input.replace('\t', " "))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// The same clean-up on a copy made up front: every input is cloned, and the
+/// ones with a tab allocate once more for the edit.
+pub fn normalize_naive(input: &str) -> String {
+    let mut s = input.to_string();
+    if s.contains('\t') {
+        s = s.replace('\t', " ");
+    }
+    s
+}
+
+fn run(out: &mut Transcript) {
+    for input in INPUTS {
+        let line = normalize(input);
+        let kind = match &line {
+            Cow::Borrowed(s) => format!("borrowed, same bytes: {}", s.as_ptr() == input.as_ptr()),
+            Cow::Owned(_) => "owned, a new String".to_string(),
+        };
+        out.say(format!("{:?} -> {:?} ({})", input, line, kind));
+    }
+
+    // Only printed when the counting allocator is on.
+    let ((), naive) = heap::measure(example_naive);
+    let ((), cow) = heap::measure(example);
+    if let (Some(naive), Some(cow)) = (naive, cow) {
+        out.say(format!("[heap] clone everywhere: {}", naive));
+        out.say(format!("[heap] clone on write: {}", cow));
+        out.say(format!(
+            "allocations avoided: {}",
+            naive.allocations - cow.allocations
+        ));
+    }
+}
+
+/// Every input through `normalize`: one allocation, for the line with a tab.
+pub fn example() {
+    for input in INPUTS {
+        black_box(normalize(input));
+    }
+}
+
+/// Every input through `normalize_naive`: one allocation per line, plus one
+/// for the line with a tab.
+pub fn example_naive() {
+    for input in INPUTS {
+        black_box(normalize_naive(input));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::trace::Tracer;
+use crate::{Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 12,
+    id: "drop",
+    title: "Custom Drop Implementations",
+    explanation: "`drop` is where the author of a type puts the code that runs when \
+a value goes out of scope, the way String returns its memory. Locals are dropped in \
+reverse order of declaration; a struct runs its own `drop` before its fields, which \
+go in declaration order; a Vec drops its elements first to last; a temporary is \
+dropped at the end of its statement; and `std::mem::drop` drops a value early by \
+taking ownership of it.",
+    body: run,
+    snippets: &[],
+};
+
+/// A value that logs its own drop.
+pub struct Noisy {
+    name: String,
+    log: Tracer,
+}
+
+impl Noisy {
+    pub fn new(log: &Tracer, name: &str) -> Noisy {
+        Noisy {
+            name: name.to_string(),
+            log: log.clone(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        self.log.record_drop(&self.name);
+    }
+}
+
+/// A struct with a `Drop` of its own and two fields that have one too.
+pub struct Pair {
+    pub first: Noisy,
+    pub second: Noisy,
+    log: Tracer,
+}
+
+impl Drop for Pair {
+    fn drop(&mut self) {
+        // Runs before the fields are dropped, while they are still usable.
+        self.log.record_drop("pair");
+    }
+}
+
+/// Runs one drop-order experiment and returns the labels in drop order.
+type Experiment = fn() -> Vec<String>;
+
+fn run(out: &mut Transcript) {
+    let experiments: [(&str, Experiment); 5] = [
+        ("locals, declared a b c", reverse_declaration_order),
+        ("struct Pair { first, second }", struct_field_order),
+        ("Vec [v0, v1, v2]", vec_element_order),
+        ("temporaries and `let _`", temporaries),
+        ("drop(a) before b", mem_drop),
+    ];
+    for (name, experiment) in experiments {
+        out.say(format!("{}: dropped {}", name, experiment().join(", ")));
+    }
+}
+
+/// `a`, `b` and `c` declared in order: dropped `c`, `b`, `a`.
+pub fn reverse_declaration_order() -> Vec<String> {
+    let log = Tracer::new();
+    {
+        let _a = Noisy::new(&log, "a");
+        let _b = Noisy::new(&log, "b");
+        let _c = Noisy::new(&log, "c");
+    }
+    log.dropped()
+}
+
+/// The struct's own `drop` first, then its fields in declaration order.
+pub fn struct_field_order() -> Vec<String> {
+    let log = Tracer::new();
+    {
+        let _pair = Pair {
+            first: Noisy::new(&log, "first"),
+            second: Noisy::new(&log, "second"),
+            log: log.clone(),
+        };
+    }
+    log.dropped()
+}
+
+/// A Vec drops its elements from first to last.
+pub fn vec_element_order() -> Vec<String> {
+    let log = Tracer::new();
+    {
+        let _v: Vec<Noisy> = ["v0", "v1", "v2"]
+            .iter()
+            .map(|name| Noisy::new(&log, name))
+            .collect();
+    }
+    log.dropped()
+}
+
+/// `let _ =` binds nothing, so the value is dropped right away; a temporary
+/// lives until the end of its statement; a named binding until the end of
+/// the scope.
+pub fn temporaries() -> Vec<String> {
+    let log = Tracer::new();
+    {
+        let _kept = Noisy::new(&log, "kept");
+        let _ = Noisy::new(&log, "wildcard");
+        let _len = Noisy::new(&log, "temporary").name().len();
+        log.record_drop("(end of scope)");
+    }
+    log.dropped()
+}
+
+/// `std::mem::drop` takes ownership, so `a` goes first this time.
+pub fn mem_drop() -> Vec<String> {
+    let log = Tracer::new();
+    {
+        let a = Noisy::new(&log, "a");
+        let _b = Noisy::new(&log, "b");
+        drop(a);
+    }
+    log.dropped()
+}
@@ -0,0 +1,105 @@
+use std::hint::black_box;
+
+use crate::trace::{EventKind, Traced, Tracer};
+use crate::{heap, Lesson, Snippet, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 7,
+    id: "functions",
+    title: "Ownership and Functions",
+    explanation: "Passing a variable to a function moves or copies it, just as \
+assignment does. A String passed by value is dropped when the function returns; an \
+i32 is Copy, so the caller can keep using it.
+
+Exercise: add code to main that uses s and x after the calls. Using x compiles; \
+using s is error E0382, as tests/compile_fail/use_after_takes_ownership.rs shows.",
+    body: run,
+    snippets: &[Snippet {
+        heading: "Ownership and Functions",
+        info: "rust",
+        code: r#"fn main() {
+    let s = String::from("hello");  // s comes into scope
+
+    takes_ownership(s);             // s's value moves into the function...
+                                    // ... and so is no longer valid here
+
+    let x = 5;                      // x comes into scope
+
+    makes_copy(x);                  // x would move into the function,
+                                    // but i32 is Copy, so it's okay to still
+                                    // use x afterward
+
+} // Here, x goes out of scope, then s. But because s's value was moved, nothing
+  // special happens.
+
+fn takes_ownership(some_string: String) { // some_string comes into scope
+    println!("{}", some_string);
+} // Here, some_string goes out of scope and `drop` is called. The backing
+  // memory is freed.
+
+fn makes_copy(some_integer: i32) { // some_integer comes into scope
+    println!("{}", some_integer);
+} // Here, some_integer goes out of scope. Nothing special happens.
+"#,
+    }],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let s = tracer.track("s", String::from("hello")); // s comes into scope
+        out.trace(&tracer);
+
+        traced_takes_ownership(s.moved_to("some_string"), &tracer, out); // s's value moves into the function...
+                                                                         // ... and so is no longer valid here
+        out.trace(&tracer);
+        out.say("back in main: some_string was dropped before takes_ownership returned");
+
+        let x = 5; // x comes into scope
+
+        makes_copy(x); // x would move into the function,
+                       // but i32 is Copy, so it's okay to still
+                       // use x afterward
+        out.say(format!("back in main: x = {} is still valid", x));
+    } // Here, x goes out of scope, then s. But because s's value was moved, nothing
+      // special happens.
+
+    let drops = tracer
+        .drain()
+        .iter()
+        .filter(|event| event.kind == EventKind::Dropped)
+        .count();
+    out.say(format!("drops at the end of main: {}", drops));
+
+    // The exercise: x can be used again, s cannot.
+    // println!("{}", s); // error[E0382]: borrow of moved value: `s`
+    out.say("exercise: using s after takes_ownership fails with E0382; using x compiles");
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+fn traced_takes_ownership(some_string: Traced<String>, tracer: &Tracer, out: &mut Transcript) {
+    // some_string comes into scope
+    out.trace(tracer);
+    out.say(format!("takes_ownership: {}", some_string));
+} // Here, some_string goes out of scope and `drop` is called. The backing
+  // memory is freed.
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let s = String::from("hello");
+    takes_ownership(s);
+
+    let x = 5;
+    makes_copy(x);
+    black_box(x);
+}
+
+pub fn takes_ownership(some_string: String) {
+    black_box(&some_string);
+} // some_string is dropped here, freeing its buffer
+
+pub fn makes_copy(some_integer: i32) {
+    black_box(some_integer);
+}
@@ -0,0 +1,102 @@
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::OnceLock;
+
+use crate::trace::Tracer;
+use crate::{Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 17,
+    id: "interior-mutability",
+    title: "Interior Mutability with Cell and RefCell",
+    explanation: "Section 2 mutates a String through `let mut s`, and the compiler \
+checks the borrows. `Cell` and `RefCell` allow mutation through a shared reference \
+instead: `Cell` by copying values in and out, `RefCell` by checking the \
+one-mutable-or-many-shared rule at run time. Breaking the rule is then not a compile \
+error but a `BorrowMutError` panic. With `Rc<RefCell<T>>` several owners can share \
+one value and still change it.",
+    body: run,
+    snippets: &[],
+};
+
+fn run(out: &mut Transcript) {
+    // Cell: no references handed out, so no borrows to check.
+    let counter = Cell::new(0);
+    let bump = || counter.set(counter.get() + 1); // a shared borrow is enough
+    bump();
+    bump();
+    out.say(format!("Cell: counter = {} after two bumps", counter.get()));
+
+    // RefCell: mutation through `&`, with the borrow rule checked at run time.
+    let s = RefCell::new(String::from("hello"));
+    s.borrow_mut().push_str(", world");
+    out.say(format!("RefCell: {}", s.borrow()));
+
+    // Compile time: `let r = &s; s.push_str("!"); r.len();` is E0502
+    // (tests/compile_fail/mutable_while_shared.rs). Run time:
+    {
+        let reader = s.borrow();
+        out.say(format!(
+            "try_borrow_mut while a Ref is alive: {:?}",
+            s.try_borrow_mut().map(|_| ())
+        ));
+        match catch_quietly(|| s.borrow_mut().push('!')) {
+            Ok(()) => out.say("borrow_mut succeeded"),
+            Err(message) => out.say(format!("borrow_mut panicked: {}", message)),
+        }
+        out.say(format!("the reader still sees {:?}", *reader));
+    }
+    s.borrow_mut().push('!'); // fine once the Ref is gone
+    out.say(format!("after the Ref is dropped: {}", s.borrow()));
+
+    // Rc<RefCell<String>>: section 4 allows one owner of a String, and the
+    // Clone section two independent copies. Rc gives several owners of one
+    // String and RefCell lets each of them change it.
+    let tracer = Tracer::new();
+    let shared = Rc::new(RefCell::new(tracer.track("shared", String::from("a"))));
+    let other_owner = Rc::clone(&shared);
+    other_owner.borrow_mut().push('b');
+    shared.borrow_mut().push('c');
+    out.say(format!(
+        "Rc<RefCell<String>>: both owners see {:?}, strong count {}",
+        shared.borrow().as_str(),
+        Rc::strong_count(&shared)
+    ));
+    drop(other_owner);
+    drop(shared);
+    out.trace(&tracer);
+}
+
+thread_local! {
+    /// Set while this thread runs a closure under `catch_quietly`.
+    static EXPECTING_PANIC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, turning a panic into its message without printing it.
+///
+/// The panic hook is process-wide, so it is replaced only once, by one that
+/// stays silent for panics on threads inside `catch_quietly` and hands every
+/// other panic to the hook that was installed before.
+pub fn catch_quietly<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    static QUIET_HOOK: OnceLock<()> = OnceLock::new();
+    QUIET_HOOK.get_or_init(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !EXPECTING_PANIC.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    let was_expecting = EXPECTING_PANIC.with(|flag| flag.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    EXPECTING_PANIC.with(|flag| flag.set(was_expecting));
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "<non-string panic>".to_string())
+    })
+}
@@ -0,0 +1,119 @@
+use std::hint::black_box;
+
+use crate::diagram::Timeline;
+use crate::trace::Tracer;
+use crate::{heap, Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 20,
+    id: "lifetimes",
+    title: "Lifetimes",
+    explanation: "The Variable Scope section says a variable is valid until the end \
+of its scope. A reference must not outlive that scope either: its lifetime is the \
+stretch of code where it is used, and it has to fit inside the scope of the owner it \
+borrows from, or the borrow would point at dropped data (error E0597). When a \
+function or a struct holds references, lifetime parameters such as `'a` tell the \
+compiler which owners those references come from; without them it cannot check, \
+which is error E0106.",
+    body: run,
+    snippets: &[],
+};
+
+/// The rejected program of the lesson, numbered like the timelines below.
+const DANGLING: &str = r#"let string1 = String::from("long string is long");
+let result;
+{
+    let string2 = String::from("xyz");
+    result = longest(string1.as_str(), string2.as_str());
+}
+println!("The longest string is {}", result); // error[E0597]"#;
+
+/// Returns the longer of two string slices. The result may borrow from either,
+/// so it lives only as long as the shorter-lived of the two.
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+/// A part of some text, borrowed rather than copied: an `Excerpt` cannot
+/// outlive the String it points into.
+#[derive(Debug)]
+pub struct Excerpt<'a> {
+    pub part: &'a str,
+}
+
+/// The text up to its first period.
+pub fn first_sentence(text: &str) -> Excerpt<'_> {
+    Excerpt {
+        part: text.split('.').next().unwrap_or(text),
+    }
+}
+
+/// Scope lines of `DANGLING`: `result` is still used after `string2` drops.
+pub fn dangling_timeline() -> Timeline {
+    Timeline::new()
+        .owner("string1", 1..=7)
+        .owner("string2", 4..=6)
+        .borrow("result", "string2", 5..=7)
+}
+
+/// The same program with the `println!` moved inside the inner scope.
+pub fn fixed_timeline() -> Timeline {
+    Timeline::new()
+        .owner("string1", 1..=7)
+        .owner("string2", 4..=6)
+        .borrow("result", "string2", 5..=6)
+}
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let string1 = tracer.track("string1", String::from("long string is long"));
+        {
+            let string2 = tracer.track("string2", String::from("xyz"));
+            let result = longest(&string1, &string2);
+            out.say(format!("The longest string is {}", result));
+        } // result's last use was above, so string2 can drop here
+    }
+    out.trace(&tracer);
+
+    let numbered: Vec<String> = DANGLING
+        .lines()
+        .enumerate()
+        .map(|(i, line)| format!("{} {}", i + 1, line))
+        .collect();
+    out.block(numbered.join("\n"));
+    let dangling = dangling_timeline();
+    out.block(&dangling);
+    for (borrow, end) in dangling.dangling() {
+        out.say(format!(
+            "{} is used after line {}, when its owner is gone: error[E0597]",
+            borrow, end
+        ));
+    }
+    out.say("move the println! inside the inner scope and the borrow fits:");
+    out.block(fixed_timeline());
+
+    {
+        let novel = tracer.track("novel", String::from("Call me Ishmael. Some years ago..."));
+        let excerpt = first_sentence(&novel);
+        out.say(format!("{:?}", excerpt));
+        // drop(novel); // error[E0505]: excerpt still borrows novel
+    }
+    out.trace(&tracer);
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code: `longest` borrows, so only the two Strings
+/// allocate.
+pub fn example() {
+    let string1 = String::from("long string is long");
+    let string2 = String::from("xyz");
+    let result = longest(&string1, &string2);
+    black_box(result);
+}
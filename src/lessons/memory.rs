@@ -0,0 +1,51 @@
+use std::hint::black_box;
+
+use crate::inspect::inspect;
+use crate::trace::Tracer;
+use crate::{heap, Lesson, Snippet, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 3,
+    id: "memory",
+    title: "Memory and Allocation",
+    explanation: "The memory a String needs is returned to the allocator when its \
+owner goes out of scope. Rust calls `drop` automatically at the closing curly bracket.",
+    body: run,
+    snippets: &[Snippet {
+        heading: "The String type",
+        info: "rust",
+        code: r#"{
+    let s = String::from("hello"); // s is valid from this point forward
+
+    // do stuff with s
+}                                  // this scope is now over, and s is no
+                                   // longer valid
+"#,
+    }],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let s = tracer.track("s", String::from("hello")); // s is valid from this point forward
+
+        out.trace(&tracer);
+
+        // do stuff with s
+        out.say(format!("s = {}", s));
+        out.say(format!("s: {}", inspect(&s)));
+    } // this scope is now over, and s is no
+      // longer valid
+
+    out.say("s went out of scope, drop returned its memory to the allocator");
+    out.trace(&tracer);
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let s = String::from("hello");
+    black_box(&s);
+} // s is dropped and its buffer freed
@@ -0,0 +1,62 @@
+//! The lessons of the walkthrough, in teaching order.
+
+pub mod boxes;
+pub mod clone;
+pub mod closures;
+pub mod copy;
+pub mod copy_types;
+pub mod cow;
+pub mod drop;
+pub mod functions;
+pub mod interior_mutability;
+pub mod lifetimes;
+pub mod memory;
+pub mod moves;
+pub mod partial_moves;
+pub mod pin;
+pub mod references;
+pub mod return_values;
+pub mod scope;
+pub mod shared_ownership;
+pub mod slices;
+pub mod string_type;
+pub mod threads;
+
+use crate::Lesson;
+
+static LESSONS: [Lesson; 21] = [
+    scope::LESSON,
+    string_type::LESSON,
+    memory::LESSON,
+    moves::LESSON,
+    clone::LESSON,
+    copy::LESSON,
+    functions::LESSON,
+    return_values::LESSON,
+    references::LESSON,
+    slices::LESSON,
+    copy_types::LESSON,
+    drop::LESSON,
+    partial_moves::LESSON,
+    closures::LESSON,
+    threads::LESSON,
+    shared_ownership::LESSON,
+    interior_mutability::LESSON,
+    boxes::LESSON,
+    cow::LESSON,
+    lifetimes::LESSON,
+    pin::LESSON,
+];
+
+/// All lessons, in the order they are taught.
+pub fn registry() -> &'static [Lesson] {
+    &LESSONS
+}
+
+/// Looks a lesson up by id (`"move"`) or by number (`"4"`).
+pub fn find(key: &str) -> Option<&'static Lesson> {
+    match key.parse::<usize>() {
+        Ok(number) => registry().iter().find(|lesson| lesson.number == number),
+        Err(_) => registry().iter().find(|lesson| lesson.id == key),
+    }
+}
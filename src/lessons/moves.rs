@@ -0,0 +1,58 @@
+use std::hint::black_box;
+
+use crate::diagram::Diagram;
+use crate::inspect::inspect;
+use crate::trace::Tracer;
+use crate::{heap, Lesson, Snippet, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 4,
+    id: "move",
+    title: "Ways Variables and Data Interact: Move",
+    explanation: "Assigning a String to another variable copies the pointer, length \
+and capacity, not the heap data. The first variable is invalidated, so only the new \
+owner frees the memory.",
+    body: run,
+    snippets: &[Snippet {
+        heading: "The String type",
+        info: "rust,should_fail,E0382",
+        code: r#"let s1 = String::from("hello");
+let s2 = s1;
+
+println!("{}, world!", s1);
+"#,
+    }],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let s1 = tracer.track("s1", String::from("hello"));
+        let before = inspect(&s1);
+        let s2 = s1.moved_to("s2");
+        let after = inspect(&s2);
+
+        //println!("{}, world!", s1); // This will not work
+        // (checked as the compile-fail case tests/compile_fail/move_then_use.rs)
+        out.say(format!("s2 = {}, s1 was moved into s2", s2));
+        out.say(format!(
+            "same heap buffer after the move: {}",
+            after.shares_buffer_with(&before)
+        ));
+        out.block(Diagram::new().moved("s1", before).string("s2", after));
+    }
+
+    // One String, one drop: s1 gave up ownership, so only s2 frees the memory.
+    out.trace(&tracer);
+    out.say(format!("frees: {}", tracer.dropped().len()));
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let s1 = String::from("hello");
+    let s2 = s1; // copies pointer, length and capacity; no new buffer
+    black_box(&s2);
+}
@@ -0,0 +1,74 @@
+use std::mem;
+
+use crate::trace::{Traced, Tracer};
+use crate::{Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 13,
+    id: "partial-moves",
+    title: "Partial Moves",
+    explanation: "Moving one field out of a struct moves only that field: the other \
+fields stay valid and are dropped with the struct, but the struct as a whole can no \
+longer be used (E0382). A value behind an index or a reference cannot be moved out \
+at all (E0507); put something in its place instead with `Option::take`, \
+`std::mem::replace` or `std::mem::take`, or `clone` it.",
+    body: run,
+    snippets: &[],
+};
+
+/// A struct without a `Drop` of its own, so its fields can be moved out one by one.
+pub struct Person {
+    pub name: Traced<String>,
+    pub email: Traced<String>,
+    pub age: u32,
+}
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let name;
+        {
+            let person = Person {
+                name: tracer.track("person.name", String::from("Ferris")),
+                email: tracer.track("person.email", String::from("ferris@example.com")),
+                age: 8,
+            };
+
+            name = person.name.moved_to("name"); // a partial move
+            out.trace(&tracer);
+
+            // The other fields are still usable, person as a whole is not:
+            // `let p2 = person;` is E0382, use of partially moved value.
+            out.say(format!(
+                "person.email = {}, person.age = {}",
+                person.email, person.age
+            ));
+        } // person goes out of scope: only the fields it still owns are dropped
+
+        out.trace(&tracer);
+        out.say(format!("name = {} is still alive", name));
+    }
+    out.trace(&tracer);
+
+    // `let first = names[0];` is E0507: it would leave a hole in the Vec.
+    let mut names = vec![String::from("a"), String::from("b"), String::from("c")];
+    let cloned = names[0].clone(); // a second buffer; names[0] untouched
+    let taken = mem::take(&mut names[1]); // leaves String::new() behind
+    let replaced = mem::replace(&mut names[2], String::from("z"));
+    out.say(format!(
+        "clone {:?}, take {:?}, replace {:?}; names is now {:?}",
+        cloned, taken, replaced, names
+    ));
+
+    let tracer = Tracer::new();
+    {
+        let mut slot = Some(tracer.track("slot", String::from("hello")));
+        let owned = slot.take().map(|value| value.moved_to("owned")); // slot is now None
+        out.say(format!(
+            "Option::take: slot is {:?}, owned = {}",
+            slot.as_deref().map(String::as_str),
+            owned.as_deref().map_or("-", String::as_str)
+        ));
+    }
+    out.trace(&tracer);
+}
@@ -0,0 +1,137 @@
+use std::hint::black_box;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::ptr;
+
+use crate::inspect::inspect;
+use crate::{heap, Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 21,
+    id: "pin",
+    title: "Pin and Self-Referential Structs",
+    explanation: "A move copies a value's stack bytes to a new place, as the Move \
+lesson shows. That is harmless until a struct stores a pointer to one of its own \
+fields: after `let s2 = s1;` the field is somewhere else and the stored pointer \
+still names the old spot. `Pin<Box<T>>` puts the value on the heap and promises it \
+never moves again; moving the Pin only moves the Box pointer, and a `PhantomPinned` \
+field stops safe code from getting the `&mut T` that `mem::swap` would need.",
+    body: run,
+    snippets: &[],
+};
+
+/// A String and a pointer to it, filled in by `init` once the struct is in its
+/// place. Nothing keeps the pointer right once the struct moves.
+pub struct SelfRef {
+    pub text: String,
+    text_ptr: *const String,
+}
+
+impl SelfRef {
+    pub fn new(text: &str) -> Self {
+        SelfRef {
+            text: String::from(text),
+            text_ptr: ptr::null(),
+        }
+    }
+
+    /// Points the struct at its own field, wherever it is right now.
+    pub fn init(&mut self) {
+        self.text_ptr = &self.text;
+    }
+
+    /// Whether the stored pointer still names this struct's own field. Only
+    /// addresses are compared; the pointer is never followed.
+    pub fn points_at_self(&self) -> bool {
+        ptr::eq(self.text_ptr, &self.text)
+    }
+
+    /// The address the struct recorded for its field.
+    pub fn stored_addr(&self) -> usize {
+        self.text_ptr as usize
+    }
+}
+
+/// The same struct, pinned: it is built in place on the heap and cannot be
+/// moved out again.
+pub struct Pinned {
+    pub text: String,
+    text_ptr: *const String,
+    _pin: PhantomPinned,
+}
+
+impl Pinned {
+    pub fn new(text: &str) -> Pin<Box<Self>> {
+        let mut boxed = Box::pin(Pinned {
+            text: String::from(text),
+            text_ptr: ptr::null(),
+            _pin: PhantomPinned,
+        });
+        let text_ptr: *const String = &boxed.text;
+        // SAFETY: only a field is written; the value itself is not moved.
+        unsafe { boxed.as_mut().get_unchecked_mut().text_ptr = text_ptr };
+        boxed
+    }
+
+    /// The field, read through the stored pointer.
+    pub fn text_via_ptr(self: Pin<&Self>) -> &str {
+        // SAFETY: the value is pinned, so the field is still where `new`
+        // recorded it, and it lives as long as `self`.
+        unsafe { &*self.text_ptr }
+    }
+
+    pub fn points_at_self(&self) -> bool {
+        ptr::eq(self.text_ptr, &self.text)
+    }
+}
+
+fn run(out: &mut Transcript) {
+    let mut s1 = SelfRef::new("hello");
+    s1.init();
+    let before = inspect(&s1.text);
+    out.say(format!(
+        "s1.text at {:#x}, stored pointer {:#x}: points at itself {}",
+        before.stack_addr,
+        s1.stored_addr(),
+        s1.points_at_self()
+    ));
+    let s2 = black_box(s1); // a move copies the struct's bytes to s2's slot
+    let after = inspect(&s2.text);
+    out.say(format!(
+        "s2.text at {:#x}, stored pointer {:#x}: points at itself {}",
+        after.stack_addr,
+        s2.stored_addr(),
+        s2.points_at_self()
+    ));
+    out.say(format!(
+        "the heap buffer did not move ({}), but the field did, and the pointer was \
+copied unchanged",
+        after.shares_buffer_with(&before)
+    ));
+
+    let p1 = Pinned::new("hello");
+    let before = inspect(&p1.text);
+    let p2 = black_box(p1); // moves the Box pointer, not the Pinned value
+    let after = inspect(&p2.text);
+    out.say(format!(
+        "pinned text at {:#x} before the move, {:#x} after: points at itself {}",
+        before.stack_addr,
+        after.stack_addr,
+        p2.points_at_self()
+    ));
+    out.say(format!(
+        "read through the pointer: {}",
+        p2.as_ref().text_via_ptr()
+    ));
+    // let inner = p2.as_mut().get_mut(); // error[E0277]: PhantomPinned is not Unpin
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code: one allocation for the Box, one for the text.
+pub fn example() {
+    let p1 = Pinned::new("hello");
+    let p2 = p1;
+    black_box(p2.as_ref().text_via_ptr());
+}
@@ -0,0 +1,79 @@
+use std::hint::black_box;
+
+use crate::inspect::inspect;
+use crate::trace::Tracer;
+use crate::{heap, Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 9,
+    id: "references",
+    title: "References and Borrowing",
+    explanation: "A reference lets a function use a value without taking ownership \
+of it, so nothing has to be handed back. `&String` borrows immutably and `&mut \
+String` mutably. At any given time a value can have either one mutable reference \
+or any number of immutable ones (E0499, E0502), and a reference never outlives \
+its owner.",
+    body: run,
+    snippets: &[],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let s1 = tracer.track("s1", String::from("hello"));
+
+        let len = calculate_length(&s1); // s1 is borrowed, not moved
+
+        out.say(format!("The length of '{}' is {}.", s1, len));
+        out.trace(&tracer);
+
+        let mut s = tracer.track("s", String::from("hello"));
+
+        change(&mut s);
+        out.say(format!("after change(&mut s): {}", s));
+
+        // Any number of shared references...
+        let r1 = &s;
+        let r2 = &s;
+        out.say(format!(
+            "r1 = {}, r2 = {}, both point at s: {}",
+            r1,
+            r2,
+            inspect(r1).stack_addr == inspect(r2).stack_addr
+        ));
+        // ...r1 and r2 are not used after this point, so a mutable one is fine.
+
+        let r3 = &mut s;
+        r3.push('!');
+        out.say(format!("r3 = {}", r3));
+        // let r4 = &mut s; r3.push('?'); // error[E0499]: two mutable borrows
+    } // The references never owned anything: only s and s1 are dropped.
+
+    out.trace(&tracer);
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let s1 = String::from("hello");
+    let len = calculate_length(&s1); // no clone, no second buffer
+    black_box((&s1, len));
+
+    let mut s = String::from("hello");
+    change(&mut s);
+    black_box(&s);
+}
+
+/// Borrows the String to measure it; the caller keeps ownership.
+#[allow(clippy::ptr_arg)] // `&String` on purpose: it is the lesson's first reference.
+pub fn calculate_length(s: &String) -> usize {
+    s.len()
+} // Here, s goes out of scope. But because it does not have ownership of what
+  // it refers to, it is not dropped.
+
+/// Appends to a String through a mutable reference.
+pub fn change(some_string: &mut String) {
+    some_string.push_str(", world");
+}
@@ -0,0 +1,159 @@
+use std::hint::black_box;
+
+use crate::trace::{Traced, Tracer};
+use crate::{heap, Lesson, Snippet, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 8,
+    id: "return-values",
+    title: "Return Values and Scope",
+    explanation: "Returning a value transfers ownership to the caller. When a \
+variable that owns heap data goes out of scope, the value is dropped unless its \
+ownership has been moved to another variable. To use a value again after \
+passing it in, a function can hand it back along with its result in a tuple.",
+    body: run,
+    snippets: &[
+        Snippet {
+            heading: "Return Values and Scope",
+            info: "rust",
+            code: r#"fn main() {
+    let s1 = gives_ownership();         // gives_ownership moves its return
+                                        // value into s1
+
+    let s2 = String::from("hello");     // s2 comes into scope
+
+    let s3 = takes_and_gives_back(s2);  // s2 is moved into
+                                        // takes_and_gives_back, which also
+                                        // moves its return value into s3
+} // Here, s3 goes out of scope and is dropped. s2 was moved, so nothing
+  // happens. s1 goes out of scope and is dropped.
+
+fn gives_ownership() -> String {             // gives_ownership will move its
+                                             // return value into the function
+                                             // that calls it
+
+    let some_string = String::from("yours"); // some_string comes into scope
+
+    some_string                              // some_string is returned and
+                                             // moves out to the calling
+                                             // function
+}
+
+// This function takes a String and returns one
+fn takes_and_gives_back(a_string: String) -> String { // a_string comes into
+                                                      // scope
+
+    a_string  // a_string is returned and moves out to the calling function
+}
+"#,
+        },
+        Snippet {
+            heading: "Return Values and Scope",
+            info: "rust",
+            code: r#"fn main() {
+    let s1 = String::from("hello");
+
+    let (s2, len) = calculate_length(s1);
+
+    println!("The length of '{}' is {}.", s2, len);
+}
+
+fn calculate_length(s: String) -> (String, usize) {
+    let length = s.len(); // len() returns the length of a String
+
+    (s, length)
+}
+"#,
+        },
+    ],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    traced_main(&tracer);
+    out.trace(&tracer);
+    out.say(format!(
+        "drop order at the end of main: {}",
+        tracer.dropped().join(", ")
+    ));
+
+    let tracer = Tracer::new();
+    {
+        let s1 = tracer.track("s1", String::from("hello"));
+
+        let (s2, len) = traced_calculate_length(s1.moved_to("s"));
+        let s2 = s2.moved_to("s2");
+        out.trace(&tracer);
+
+        out.say(format!("The length of '{}' is {}.", s2, len));
+    }
+    out.trace(&tracer);
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// Runs the section's `main` with tracing and returns the labels of the
+/// values in the order they were dropped.
+pub fn drop_order() -> Vec<String> {
+    let tracer = Tracer::new();
+    traced_main(&tracer);
+    tracer.dropped()
+}
+
+fn traced_main(tracer: &Tracer) {
+    // gives_ownership moves its return value into s1
+    let s1 = traced_gives_ownership(tracer).moved_to("s1");
+
+    let s2 = tracer.track("s2", String::from("hello")); // s2 comes into scope
+
+    // s2 is moved into takes_and_gives_back, which also moves its return
+    // value into s3
+    let s3 = traced_takes_and_gives_back(s2.moved_to("a_string")).moved_to("s3");
+
+    let _ = (&s1, &s3);
+} // Here, s3 goes out of scope and is dropped. s2 was moved, so nothing
+  // happens. s1 goes out of scope and is dropped.
+
+fn traced_gives_ownership(tracer: &Tracer) -> Traced<String> {
+    tracer.track("some_string", String::from("yours")) // moves out to the caller
+}
+
+fn traced_takes_and_gives_back(a_string: Traced<String>) -> Traced<String> {
+    a_string // a_string is returned and moves out to the calling function
+}
+
+fn traced_calculate_length(s: Traced<String>) -> (Traced<String>, usize) {
+    let length = s.len(); // len() returns the length of a String
+
+    (s, length)
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let s1 = gives_ownership();
+    let s2 = String::from("hello");
+    let s3 = takes_and_gives_back(s2);
+    let (s4, len) = calculate_length(s3); // s3 moves in and comes back as s4
+    black_box((&s1, &s4, len));
+}
+
+// Kept in the article's shape: the binding is what moves out.
+#[allow(clippy::let_and_return)]
+pub fn gives_ownership() -> String {
+    let some_string = String::from("yours");
+
+    some_string
+}
+
+pub fn takes_and_gives_back(a_string: String) -> String {
+    a_string
+}
+
+/// Returns the String it was given along with its length, so the caller
+/// gets ownership back.
+pub fn calculate_length(s: String) -> (String, usize) {
+    let length = s.len(); // len() returns the length of a String
+
+    (s, length)
+}
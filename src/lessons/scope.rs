@@ -0,0 +1,47 @@
+use std::hint::black_box;
+
+use crate::trace::Tracer;
+use crate::{heap, Lesson, Snippet, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 1,
+    id: "scope",
+    title: "Variable Scope",
+    explanation: "A variable is valid from the point where it is declared until the \
+end of the current scope. When the scope is over, the variable is no longer valid.",
+    body: run,
+    snippets: &[Snippet {
+        heading: "Example",
+        info: "rust",
+        code: r#"fn main() {
+    {                      // s is not valid here, it’s not yet declared
+        let s = "hello";   // s is valid from this point forward
+
+        // do stuff with s
+    }                      // this scope is now over, and s is no longer valid
+}
+"#,
+    }],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        // s is not valid here, it’s not yet declared
+        let s = tracer.track("s", "hello"); // s is valid from this point forward
+
+        // do stuff with s
+        out.say(format!("s = {}", s));
+    } // this scope is now over, and s is no longer valid
+
+    out.trace(&tracer);
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let s = "hello"; // a literal lives in the binary, not on the heap
+    black_box(s);
+}
@@ -0,0 +1,124 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+use std::thread;
+
+use crate::trace::{Traced, Tracer};
+use crate::{Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 16,
+    id: "shared-ownership",
+    title: "Shared Ownership with Rc and Arc",
+    explanation: "When one owner is not enough, `Rc<T>` counts its owners: \
+`Rc::clone` adds an owner without copying the heap data, and the value is dropped \
+when the strong count reaches zero. `Arc<T>` does the same with atomic counts so \
+owners can live on different threads. A `Weak` pointer does not count as an owner, \
+which is how a reference cycle, which would otherwise never reach zero and leak, \
+is broken.",
+    body: run,
+    snippets: &[],
+};
+
+/// A list node whose links are strong `Rc`s: two of them pointing at each
+/// other keep each other alive forever.
+pub struct StrongNode {
+    pub name: Traced<String>,
+    pub next: RefCell<Option<Rc<StrongNode>>>,
+}
+
+/// The same node with a `Weak` back link, which does not keep its target alive.
+pub struct WeakNode {
+    pub name: Traced<String>,
+    pub next: RefCell<Option<Rc<WeakNode>>>,
+    pub prev: RefCell<Weak<WeakNode>>,
+}
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+
+    // Section 4's `let s2 = s1;` leaves one owner. Rc::clone makes two.
+    let a = Rc::new(tracer.track("shared", String::from("hello")));
+    out.say(format!(
+        "a: strong {}, weak {}",
+        Rc::strong_count(&a),
+        Rc::weak_count(&a)
+    ));
+    let b = Rc::clone(&a);
+    let w = Rc::downgrade(&a);
+    out.say(format!(
+        "after Rc::clone and Rc::downgrade: strong {}, weak {}, same allocation: {}",
+        Rc::strong_count(&a),
+        Rc::weak_count(&a),
+        Rc::ptr_eq(&a, &b)
+    ));
+    drop(b);
+    out.say(format!("drop(b): strong {}", Rc::strong_count(&a)));
+    out.trace(&tracer); // nothing dropped yet: a still owns the String
+    drop(a);
+    out.say(format!(
+        "drop(a): strong 0, the weak pointer upgrades to {:?}",
+        w.upgrade().map(|s| s.len())
+    ));
+    out.trace(&tracer);
+
+    // Arc: the same counting, safe to share across threads.
+    let shared = Arc::new(tracer.track("arc", String::from("across threads")));
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let owner = Arc::clone(&shared);
+            thread::spawn(move || owner.len())
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("thread panicked");
+    }
+    out.say(format!(
+        "Arc after the threads joined: strong {}",
+        Arc::strong_count(&shared)
+    ));
+    drop(shared);
+    out.trace(&tracer);
+
+    out.say(format!("strong cycle, leaked: {:?}", strong_cycle()));
+    out.say(format!("weak back link, leaked: {:?}", weak_back_link()));
+}
+
+/// Links two nodes to each other with strong pointers and drops both
+/// handles. Returns the labels that were never dropped.
+pub fn strong_cycle() -> Vec<String> {
+    let tracer = Tracer::new();
+    {
+        let a = Rc::new(StrongNode {
+            name: tracer.track("a", String::from("a")),
+            next: RefCell::new(None),
+        });
+        let b = Rc::new(StrongNode {
+            name: tracer.track("b", String::from("b")),
+            next: RefCell::new(Some(Rc::clone(&a))),
+        });
+        *a.next.borrow_mut() = Some(Rc::clone(&b));
+        // a and b each have a strong count of 2; dropping the handles
+        // below only brings them down to 1.
+    }
+    tracer.alive()
+}
+
+/// The same two nodes, with the link back from `b` to `a` made `Weak`.
+pub fn weak_back_link() -> Vec<String> {
+    let tracer = Tracer::new();
+    {
+        let a = Rc::new(WeakNode {
+            name: tracer.track("a", String::from("a")),
+            next: RefCell::new(None),
+            prev: RefCell::new(Weak::new()),
+        });
+        let b = Rc::new(WeakNode {
+            name: tracer.track("b", String::from("b")),
+            next: RefCell::new(None),
+            prev: RefCell::new(Rc::downgrade(&a)),
+        });
+        *a.next.borrow_mut() = Some(Rc::clone(&b));
+    }
+    tracer.alive()
+}
@@ -0,0 +1,105 @@
+use std::hint::black_box;
+
+use crate::inspect::inspect;
+use crate::{heap, Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 10,
+    id: "slices",
+    title: "The Slice Type",
+    explanation: "A string slice, `&str`, is a reference to part of a String: a \
+pointer into the String's heap buffer and a length. A string literal is a slice \
+too, pointing into the program binary. An index into a String knows nothing about \
+the String and goes stale when it changes; a slice borrows the String, so \
+changing it while the slice is alive does not compile (E0502).",
+    body: run,
+    snippets: &[],
+};
+
+fn run(out: &mut Transcript) {
+    // An index is just a number: nothing ties it to s.
+    let mut s = String::from("hello world");
+    let word = first_word_index(&s); // word will get the value 5
+
+    s.clear(); // this empties the String, making it equal to ""
+
+    out.say(format!(
+        "index {} after s.clear(): s[..{}] is {:?}",
+        word,
+        word,
+        s.get(..word)
+    ));
+
+    // A slice borrows s, so s.clear() here would not compile
+    // (tests/compile_fail/clear_while_sliced.rs).
+    let s = String::from("hello world");
+    let word = first_word(&s);
+    let layout = inspect(&s);
+    out.say(format!("first_word = {:?}", word));
+    out.say(format!("s:    {}", layout));
+    out.say(format!(
+        "word: ptr {:#x}, len {}, inside s's buffer at offset {:?}",
+        word.as_ptr() as usize,
+        word.len(),
+        layout.offset_of(word)
+    ));
+
+    let world = &s[6..11];
+    out.say(format!(
+        "&s[6..11] = {:?} at offset {:?}",
+        world,
+        layout.offset_of(world)
+    ));
+
+    // A literal is a slice into the binary, not into a heap buffer.
+    let literal: &'static str = "hello";
+    out.say(format!(
+        "literal {:?}: ptr {:#x}, inside s's buffer: {}",
+        literal,
+        literal.as_ptr() as usize,
+        layout.offset_of(literal).is_some()
+    ));
+    out.say(format!(
+        "first_word works on both: {:?} and {:?}",
+        first_word(&s[..]),
+        first_word(literal)
+    ));
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let s = String::from("hello world");
+    let word = first_word(&s); // borrows part of s; nothing is copied
+    black_box(word);
+}
+
+/// The end of the first word as a byte index. The index stays the same
+/// whatever happens to `s` afterwards.
+#[allow(clippy::ptr_arg)] // `&String` on purpose: the index belongs to this String.
+pub fn first_word_index(s: &String) -> usize {
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return i;
+        }
+    }
+
+    s.len()
+}
+
+/// The first word of `s` as a slice of it.
+pub fn first_word(s: &str) -> &str {
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+
+    s
+}
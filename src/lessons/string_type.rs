@@ -0,0 +1,66 @@
+use std::hint::black_box;
+
+use crate::inspect::{inspect, BufferChange};
+use crate::trace::Tracer;
+use crate::{heap, Lesson, Snippet, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 2,
+    id: "string-type",
+    title: "The String Type",
+    explanation: "String literals are immutable and hardcoded into the program. A \
+`String` is allocated on the heap, so it can hold an amount of text that is unknown \
+at compile time, and it can be mutated.",
+    body: run,
+    snippets: &[
+        Snippet {
+            heading: "The String type",
+            info: "rust",
+            code: r#"let s = String::from("hello");
+"#,
+        },
+        Snippet {
+            heading: "The String type",
+            info: "rust",
+            code: r#"let mut s = String::from("hello");
+
+s.push_str(", world!"); // push_str() appends a literal to a String
+
+println!("{}", s); // This will print `hello, world!`
+"#,
+        },
+    ],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+    {
+        let mut s = tracer.track("s", String::from("hello"));
+
+        let before = inspect(&s);
+        out.say(format!("before: {}", before));
+
+        s.push_str(", world!"); // push_str() appends a literal to a String
+
+        let after = inspect(&s);
+        out.say(format!("after:  {}", after));
+        out.say(format!(
+            "push_str: {}",
+            BufferChange::between(&before, &after)
+        ));
+
+        out.say(s.to_string()); // This will print `hello, world!
+    }
+
+    out.trace(&tracer);
+
+    let ((), stats) = heap::measure(example);
+    out.heap(stats);
+}
+
+/// The section as plain code, without tracing.
+pub fn example() {
+    let mut s = String::from("hello");
+    s.push_str(", world!"); // grows the heap buffer
+    black_box(&s);
+}
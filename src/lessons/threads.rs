@@ -0,0 +1,85 @@
+use std::sync::mpsc;
+use std::thread;
+
+use crate::trace::{EventKind, Traced, Tracer};
+use crate::{Lesson, Transcript};
+
+pub const LESSON: Lesson = Lesson {
+    number: 15,
+    id: "threads",
+    title: "Threads and Ownership",
+    explanation: "A spawned thread may outlive the function that started it, so \
+its closure must own everything it uses: without `move` it would only borrow, which \
+is error E0373. Moving a String into a thread or sending it down a channel \
+transfers ownership, so exactly one thread can use it and that thread drops it. \
+This is the Move section's rule at work, and it is what makes data races \
+impossible.",
+    body: run,
+    snippets: &[],
+};
+
+fn run(out: &mut Transcript) {
+    let tracer = Tracer::new();
+
+    // Moving a String into a thread: the worker owns it and drops it.
+    let s = tracer.track("s", String::from("hello from main"));
+    let worker = spawn_named("worker", move || {
+        let s = s.moved_to("worker's s");
+        s.len()
+    });
+    let len = worker.join().expect("worker panicked");
+    // println!("{}", s); // error[E0382]: s was moved into the closure
+    out.say(format!("worker measured {} bytes", len));
+    out.trace(&tracer);
+
+    // Handing ownership back: the thread returns the String through join.
+    let s = tracer.track("s", String::from("hello"));
+    let worker = spawn_named("appender", move || {
+        let mut s = s.moved_to("appender's s");
+        s.push_str(", world");
+        s
+    });
+    let s = worker
+        .join()
+        .expect("appender panicked")
+        .moved_to("main's s");
+    out.say(format!("appender handed back {:?}", *s));
+    drop(s);
+    out.trace(&tracer);
+
+    // Channels: send moves the value to whichever thread receives it.
+    let (tx, rx) = mpsc::channel::<Traced<String>>();
+    let producer_tracer = tracer.clone();
+    let producer = spawn_named("producer", move || {
+        for word in ["one", "two"] {
+            let message = producer_tracer.track("message", word.to_string());
+            tx.send(message).expect("receiver hung up");
+            // println!("{}", message); // error[E0382]: message was sent
+        }
+    });
+    for message in rx {
+        let message = message.moved_to("received");
+        out.say(format!("main received {:?}", *message));
+    }
+    producer.join().expect("producer panicked");
+    out.trace(&tracer);
+
+    for event in tracer.events() {
+        if event.kind == EventKind::Dropped {
+            out.say(format!(
+                "{} was dropped on thread {}",
+                event.label, event.thread
+            ));
+        }
+    }
+}
+
+fn spawn_named<T: Send + 'static>(
+    name: &str,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> thread::JoinHandle<T> {
+    thread::Builder::new()
+        .name(name.to_string())
+        .spawn(f)
+        .expect("spawn thread")
+}
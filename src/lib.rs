@@ -0,0 +1,23 @@
+//! The ownership walkthrough from `article.md`, as a library.
+//!
+//! Every numbered section of the walkthrough is a [`Lesson`]: an id, a
+//! title, the explanation from the article and a runnable body. The
+//! [`registry`] lists them in the order they are taught, so the binary and
+//! any training material linking this crate see the same sections.
+
+pub mod article;
+pub mod cli;
+pub mod compile_fail;
+pub mod diagram;
+pub mod explain;
+pub mod heap;
+pub mod inspect;
+pub mod json;
+pub mod lesson;
+pub mod lessons;
+pub mod rustc;
+pub mod sync;
+pub mod trace;
+
+pub use lesson::{Lesson, Snippet, Transcript};
+pub use lessons::{find, registry};
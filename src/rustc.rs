@@ -0,0 +1,115 @@
+//! Runs the local `rustc` (or `$RUSTC`) on snippets, offline.
+//!
+//! Each call works in its own scratch directory under the system temp dir
+//! and removes it afterwards.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// What rustc said about a piece of code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compilation {
+    pub success: bool,
+    /// The error codes reported, in order, without duplicates.
+    pub codes: Vec<String>,
+    /// rustc's diagnostics, one per line.
+    pub diagnostics: String,
+}
+
+/// A snippet that was built and, if it compiled, run.
+#[derive(Debug)]
+pub struct Execution {
+    pub compilation: Compilation,
+    /// The output of the program, or `None` if it did not compile.
+    pub output: Option<Output>,
+}
+
+/// Type-checks `source` as a binary crate without linking it.
+pub fn check(name: &str, source: &str) -> io::Result<Compilation> {
+    let dir = ScratchDir::new(name)?;
+    let file = dir.write(name, source)?;
+    let output = rustc(&dir.0, &file, "metadata")?;
+    Ok(compilation(&output))
+}
+
+/// Builds `source` as a binary crate and runs it if it compiled.
+pub fn build_and_run(name: &str, source: &str) -> io::Result<Execution> {
+    let dir = ScratchDir::new(name)?;
+    let file = dir.write(name, source)?;
+    let output = rustc(&dir.0, &file, "link")?;
+    let compilation = compilation(&output);
+    let output = if compilation.success {
+        Some(Command::new(dir.0.join(name)).output()?)
+    } else {
+        None
+    };
+    Ok(Execution {
+        compilation,
+        output,
+    })
+}
+
+fn rustc(dir: &Path, file: &Path, emit: &str) -> io::Result<Output> {
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    Command::new(rustc)
+        .args(["--edition", "2021", "--crate-type", "bin", "--emit", emit])
+        .args(["--error-format", "short", "-A", "warnings"])
+        .arg("--out-dir")
+        .arg(dir)
+        .arg(file)
+        .output()
+}
+
+fn compilation(output: &Output) -> Compilation {
+    let diagnostics = String::from_utf8_lossy(&output.stderr).into_owned();
+    let mut codes: Vec<String> = Vec::new();
+    for code in diagnostics.lines().filter_map(error_code) {
+        if !codes.iter().any(|known| known == code) {
+            codes.push(code.to_string());
+        }
+    }
+    Compilation {
+        success: output.status.success(),
+        codes,
+        diagnostics,
+    }
+}
+
+/// The code of a short-format diagnostic line, e.g. `E0382` out of
+/// `case.rs:5:20: error[E0382]: borrow of moved value: `s1``.
+fn error_code(line: &str) -> Option<&str> {
+    let start = line.find("error[")? + "error[".len();
+    let len = line[start..].find(']')?;
+    Some(&line[start..start + len])
+}
+
+struct ScratchDir(PathBuf);
+
+impl ScratchDir {
+    fn new(name: &str) -> io::Result<ScratchDir> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "rust-live-6-ownership-{}-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed),
+            name
+        ));
+        fs::create_dir_all(&dir)?;
+        Ok(ScratchDir(dir))
+    }
+
+    fn write(&self, name: &str, source: &str) -> io::Result<PathBuf> {
+        let file = self.0.join(format!("{}.rs", name));
+        fs::write(&file, source)?;
+        Ok(file)
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
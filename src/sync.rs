@@ -0,0 +1,217 @@
+//! Keeps the code blocks of `article.md` in sync with the lessons.
+//!
+//! The lesson registry is the source of truth. Each [`Snippet`] names the
+//! article heading it belongs under; the snippets of a heading are paired,
+//! in registry order, with the Rust blocks the article has under it.
+//! [`check`] reports where the two disagree and [`rewrite`] replaces the
+//! article's blocks with the lessons' snippets.
+
+use std::fmt;
+
+use crate::article::{self, CodeBlock};
+use crate::{registry, Lesson, Snippet};
+
+/// A place where the article and the lessons disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    /// An article block differs from the snippet of the lesson it belongs to.
+    Drift {
+        lesson: &'static str,
+        heading: String,
+        /// Line of the article block's opening fence.
+        line: usize,
+        /// The first line that differs, counted from the top of the block.
+        at: usize,
+        expected: String,
+        found: String,
+    },
+    /// A lesson snippet has no block in the article.
+    Missing {
+        lesson: &'static str,
+        heading: &'static str,
+    },
+    /// An article block that no lesson covers.
+    Uncovered { heading: String, line: usize },
+}
+
+impl Difference {
+    /// Whether the difference means the article is out of date. Uncovered
+    /// blocks only mean the lessons have not caught up with the article yet.
+    pub fn is_drift(&self) -> bool {
+        !matches!(self, Difference::Uncovered { .. })
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difference::Drift {
+                lesson,
+                heading,
+                line,
+                at,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} ({}): differs from lesson `{}` at block line {}\n  lesson:  {}\n  article: {}",
+                line, heading, lesson, at, expected, found
+            ),
+            Difference::Missing { lesson, heading } => write!(
+                f,
+                "lesson `{}` has a snippet for \"{}\" that the article does not show",
+                lesson, heading
+            ),
+            Difference::Uncovered { heading, line } => {
+                write!(f, "line {} ({}): no lesson covers this block", line, heading)
+            }
+        }
+    }
+}
+
+/// Compares the article `text` with the lesson snippets.
+pub fn check(text: &str) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    for pair in pairs(text) {
+        match pair {
+            Pair::Matched(block, lesson, snippet) => {
+                if let Some(difference) = compare(&block, lesson, snippet) {
+                    differences.push(difference);
+                }
+            }
+            Pair::Missing(lesson, snippet) => differences.push(Difference::Missing {
+                lesson: lesson.id,
+                heading: snippet.heading,
+            }),
+            Pair::Uncovered(block) => differences.push(Difference::Uncovered {
+                heading: block.heading,
+                line: block.line,
+            }),
+        }
+    }
+    differences
+}
+
+/// Rewrites the article's code blocks from the lesson snippets they belong to.
+/// Blocks no lesson covers are left alone, and missing snippets are not added.
+pub fn rewrite(text: &str) -> String {
+    let mut replacements: Vec<(CodeBlock, &'static Snippet)> = pairs(text)
+        .into_iter()
+        .filter_map(|pair| match pair {
+            Pair::Matched(block, lesson, snippet) => compare(&block, lesson, snippet)
+                .is_some()
+                .then_some((block, snippet)),
+            _ => None,
+        })
+        .collect();
+    replacements.sort_by_key(|(block, _)| block.line);
+
+    let lines: Vec<&str> = text.lines().collect();
+    let mut out = String::new();
+    let mut next = 0;
+    for (block, snippet) in replacements {
+        for line in &lines[next..block.line - 1] {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push_str("```");
+        out.push_str(snippet.info);
+        out.push('\n');
+        out.push_str(&normalize(snippet.code));
+        out.push_str("```\n");
+        next = block.end;
+    }
+    for line in &lines[next..] {
+        out.push_str(line);
+        out.push('\n');
+    }
+    if !text.ends_with('\n') {
+        out.pop();
+    }
+    out
+}
+
+enum Pair {
+    Matched(CodeBlock, &'static Lesson, &'static Snippet),
+    Missing(&'static Lesson, &'static Snippet),
+    Uncovered(CodeBlock),
+}
+
+fn pairs(text: &str) -> Vec<Pair> {
+    let blocks = article::code_blocks(text);
+    let snippets: Vec<(&'static Lesson, &'static Snippet)> = registry()
+        .iter()
+        .flat_map(|lesson| lesson.snippets.iter().map(move |snippet| (lesson, snippet)))
+        .collect();
+
+    let mut headings: Vec<&str> = Vec::new();
+    for heading in blocks
+        .iter()
+        .map(|block| block.heading.as_str())
+        .chain(snippets.iter().map(|(_, snippet)| snippet.heading))
+    {
+        if !headings.contains(&heading) {
+            headings.push(heading);
+        }
+    }
+
+    let mut pairs = Vec::new();
+    for heading in headings {
+        let mut blocks = blocks.iter().filter(|block| block.heading == heading);
+        let mut snippets = snippets
+            .iter()
+            .filter(|(_, snippet)| snippet.heading == heading);
+        loop {
+            match (blocks.next(), snippets.next()) {
+                (Some(block), Some((lesson, snippet))) => {
+                    pairs.push(Pair::Matched(block.clone(), lesson, snippet))
+                }
+                (None, Some((lesson, snippet))) => pairs.push(Pair::Missing(lesson, snippet)),
+                (Some(block), None) => pairs.push(Pair::Uncovered(block.clone())),
+                (None, None) => break,
+            }
+        }
+    }
+    pairs
+}
+
+fn compare(block: &CodeBlock, lesson: &'static Lesson, snippet: &Snippet) -> Option<Difference> {
+    let expected = format!("```{}\n{}", snippet.info, normalize(snippet.code));
+    let found = format!("```{}\n{}", block.info, normalize(&block.code));
+    let at = expected
+        .lines()
+        .zip(found.lines())
+        .position(|(a, b)| a != b)
+        .or_else(|| {
+            (expected.lines().count() != found.lines().count())
+                .then(|| expected.lines().count().min(found.lines().count()))
+        })?;
+    Some(Difference::Drift {
+        lesson: lesson.id,
+        heading: block.heading.clone(),
+        line: block.line,
+        at,
+        expected: expected
+            .lines()
+            .nth(at)
+            .unwrap_or("<end of block>")
+            .to_string(),
+        found: found
+            .lines()
+            .nth(at)
+            .unwrap_or("<end of block>")
+            .to_string(),
+    })
+}
+
+/// Code without common indentation, trailing whitespace or surrounding blank lines.
+fn normalize(code: &str) -> String {
+    let lines: Vec<&str> = code.lines().collect();
+    let dedented = article::dedent(&lines);
+    let trimmed = dedented.trim_matches('\n');
+    if trimmed.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", trimmed)
+    }
+}
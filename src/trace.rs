@@ -0,0 +1,260 @@
+//! Drop tracing: a wrapper that logs when a value is created, moved, cloned
+//! and dropped.
+//!
+//! ```
+//! use rust_live_6_ownership::trace::Tracer;
+//!
+//! let tracer = Tracer::new();
+//! {
+//!     let s = tracer.track("s", String::from("hello"));
+//!     assert_eq!(*s, "hello");
+//! }
+//! assert_eq!(tracer.dropped(), ["s"]);
+//! ```
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// What happened to a traced value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventKind {
+    Created,
+    /// The value was cloned from the value with this label.
+    Cloned {
+        from: String,
+    },
+    /// The value was moved out of the variable with this label.
+    Moved {
+        from: String,
+    },
+    Dropped,
+}
+
+/// One entry of the event log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub kind: EventKind,
+    /// The variable that owns the value after the event.
+    pub label: String,
+    /// The source line of the event. Drops have none: `Drop` cannot see its caller.
+    pub line: Option<u32>,
+    /// The name of the thread the event happened on.
+    pub thread: String,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            EventKind::Created => write!(f, "{} created", self.label)?,
+            EventKind::Cloned { from } => write!(f, "{} cloned from {}", self.label, from)?,
+            EventKind::Moved { from } => write!(f, "{} moved into {}", from, self.label)?,
+            EventKind::Dropped => write!(f, "{} dropped", self.label)?,
+        }
+        if let Some(line) = self.line {
+            write!(f, " (line {})", line)?;
+        }
+        if self.thread != "main" {
+            write!(f, " on thread {}", self.thread)?;
+        }
+        Ok(())
+    }
+}
+
+/// A shared event log. Cloning a tracer gives another handle to the same log.
+#[derive(Debug, Clone, Default)]
+pub struct Tracer {
+    log: Arc<Mutex<Log>>,
+}
+
+#[derive(Debug, Default)]
+struct Log {
+    events: Vec<Event>,
+    /// Events before this index have already been handed out by `drain`.
+    drained: usize,
+}
+
+impl Tracer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps `value` so that its life is recorded under `label`.
+    #[track_caller]
+    pub fn track<T>(&self, label: &str, value: T) -> Traced<T> {
+        self.record(EventKind::Created, label, Some(Location::caller().line()));
+        Traced {
+            value,
+            label: label.to_string(),
+            tracer: self.clone(),
+        }
+    }
+
+    /// Every event recorded so far.
+    pub fn events(&self) -> Vec<Event> {
+        self.lock().events.clone()
+    }
+
+    /// The events recorded since the previous call to `drain`.
+    pub fn drain(&self) -> Vec<Event> {
+        let mut log = self.lock();
+        let start = log.drained;
+        log.drained = log.events.len();
+        log.events[start..].to_vec()
+    }
+
+    /// The labels of the dropped values, in the order they were dropped.
+    pub fn dropped(&self) -> Vec<String> {
+        self.lock()
+            .events
+            .iter()
+            .filter(|event| event.kind == EventKind::Dropped)
+            .map(|event| event.label.clone())
+            .collect()
+    }
+
+    /// The labels of the values created and not dropped yet, under the
+    /// name of their current owner. Anything left here after its owners
+    /// are gone has leaked.
+    pub fn alive(&self) -> Vec<String> {
+        let mut alive: Vec<String> = Vec::new();
+        for event in &self.lock().events {
+            match &event.kind {
+                EventKind::Created | EventKind::Cloned { .. } => alive.push(event.label.clone()),
+                EventKind::Moved { from } => {
+                    if let Some(owner) = alive.iter_mut().find(|owner| *owner == from) {
+                        *owner = event.label.clone();
+                    }
+                }
+                EventKind::Dropped => {
+                    if let Some(i) = alive.iter().position(|owner| *owner == event.label) {
+                        alive.remove(i);
+                    }
+                }
+            }
+        }
+        alive
+    }
+
+    /// Records that `label` was dropped, for types with their own `Drop`
+    /// implementation that want to show up in the same log as [`Traced`].
+    pub fn record_drop(&self, label: &str) {
+        self.record(EventKind::Dropped, label, None);
+    }
+
+    fn record(&self, kind: EventKind, label: &str, line: Option<u32>) {
+        let thread = std::thread::current()
+            .name()
+            .unwrap_or("<unnamed>")
+            .to_string();
+        self.lock().events.push(Event {
+            kind,
+            label: label.to_string(),
+            line,
+            thread,
+        });
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Log> {
+        // A panic while the lock is held cannot leave the log half-written,
+        // so a poisoned log is still worth reading.
+        self.log
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A value whose creation, clones, moves and drop end up in a [`Tracer`] log.
+///
+/// `Traced<T>` derefs to `T`, so a `Traced<String>` can be used like the
+/// `String` it wraps.
+pub struct Traced<T> {
+    value: T,
+    label: String,
+    tracer: Tracer,
+}
+
+impl<T> Traced<T> {
+    /// Records a move into the variable `label`.
+    ///
+    /// A plain `let s2 = s1;` moves a `Traced` like any other value, but
+    /// nothing can observe it; `let s2 = s1.moved_to("s2");` makes the same
+    /// move and logs it.
+    #[track_caller]
+    pub fn moved_to(mut self, label: &str) -> Self {
+        let from = std::mem::replace(&mut self.label, label.to_string());
+        self.tracer.record(
+            EventKind::Moved { from },
+            label,
+            Some(Location::caller().line()),
+        );
+        self
+    }
+
+    /// The variable that currently owns the value.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl<T: Clone> Traced<T> {
+    /// Clones the value into the variable `label`.
+    #[track_caller]
+    pub fn clone_as(&self, label: &str) -> Self {
+        self.tracer.record(
+            EventKind::Cloned {
+                from: self.label.clone(),
+            },
+            label,
+            Some(Location::caller().line()),
+        );
+        Traced {
+            value: self.value.clone(),
+            label: label.to_string(),
+            tracer: self.tracer.clone(),
+        }
+    }
+}
+
+impl<T: Clone> Clone for Traced<T> {
+    #[track_caller]
+    fn clone(&self) -> Self {
+        self.clone_as(&format!("{} (clone)", self.label))
+    }
+}
+
+impl<T> Drop for Traced<T> {
+    fn drop(&mut self) {
+        self.tracer.record(EventKind::Dropped, &self.label, None);
+    }
+}
+
+impl<T> Deref for Traced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Traced<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Traced<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Traced<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Traced")
+            .field("label", &self.label)
+            .field("value", &self.value)
+            .finish()
+    }
+}
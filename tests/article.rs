@@ -0,0 +1,32 @@
+//! The Rust blocks of `article.md` behave as their fence annotations say.
+
+use rust_live_6_ownership::article::{self, Mode};
+
+#[test]
+fn article_code_blocks_verify() {
+    let blocks = article::code_blocks(article::TEXT);
+    assert!(!blocks.is_empty(), "article.md has no rust blocks");
+    assert!(blocks
+        .iter()
+        .any(|block| matches!(block.mode, Mode::ShouldFail(_))));
+
+    let failures: Vec<String> = blocks
+        .iter()
+        .filter_map(|block| {
+            article::verify(block)
+                .err()
+                .map(|failure| format!("line {} ({}): {}", block.line, block.heading, failure))
+        })
+        .collect();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
+
+#[test]
+fn fragments_are_wrapped_in_main() {
+    let text = "# Heading\n\n```rust,no_run\n    let x = 5;\n```\n\n```text\nnot rust\n```\n";
+    let blocks = article::code_blocks(text);
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].heading, "Heading");
+    assert_eq!(blocks[0].mode, Mode::NoRun);
+    assert_eq!(blocks[0].program(), "fn main() {\n    let x = 5;\n}\n");
+}
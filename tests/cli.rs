@@ -0,0 +1,89 @@
+//! Parsing the command line and the exit codes of its errors.
+
+use rust_live_6_ownership::cli::{self, CliError, Command, Selection};
+
+fn usage_message(args: &[&str]) -> String {
+    match cli::parse(args.iter().copied()) {
+        Err(CliError::Usage(message)) => message,
+        other => panic!("{:?}: expected a usage error, got {:?}", args, other),
+    }
+}
+
+#[test]
+fn parses_the_commands() {
+    let parse = |args: &[&str]| cli::parse(args.iter().copied()).expect("valid command line");
+    assert_eq!(parse(&[]), Command::Run(Selection::All));
+    assert_eq!(parse(&["run", "--all"]), Command::Run(Selection::All));
+    assert_eq!(
+        parse(&["run", "4"]),
+        Command::Run(Selection::One("4".into()))
+    );
+    assert_eq!(parse(&["explain", "move"]), Command::Explain("move".into()));
+    assert_eq!(parse(&["diagnose"]), Command::Diagnose(None));
+    assert_eq!(
+        parse(&["sync", "--write", "notes.md"]),
+        Command::Sync {
+            path: Some("notes.md".into()),
+            write: true
+        }
+    );
+    assert_eq!(parse(&["--help"]), Command::Help);
+}
+
+#[test]
+fn usage_errors_name_the_bad_argument() {
+    assert_eq!(
+        usage_message(&["run", "--bogus"]),
+        "`run` does not take `--bogus`"
+    );
+    assert_eq!(
+        usage_message(&["explain", "move", "extra"]),
+        "`explain` does not take `extra`"
+    );
+    assert_eq!(
+        usage_message(&["sync", "--force"]),
+        "`sync` does not take `--force`"
+    );
+    assert_eq!(
+        usage_message(&["sync", "notes.md", "--write"]),
+        "`--write` must come before the path"
+    );
+    assert_eq!(
+        usage_message(&["run", "4", "--all"]),
+        "`run` takes a lesson or `--all`, not both"
+    );
+    assert_eq!(
+        usage_message(&["frobnicate"]),
+        "unknown command `frobnicate`"
+    );
+}
+
+#[test]
+fn usage_errors_exit_with_2() {
+    let err = cli::parse(["run"]).unwrap_err();
+    assert_eq!(err.exit_code(), 2);
+}
+
+#[test]
+fn unknown_sections_exit_with_1() {
+    let command = cli::parse(["run", "no-such-lesson"]).unwrap();
+    let err = cli::execute(&command, &mut Vec::new()).unwrap_err();
+    assert!(matches!(err, CliError::UnknownSection(ref key) if key == "no-such-lesson"));
+    assert_eq!(err.exit_code(), 1);
+}
+
+#[test]
+fn list_aligns_the_titles() {
+    let mut out = Vec::new();
+    cli::execute(&Command::List, &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let columns: Vec<usize> = out.lines().map(title_column).collect();
+    assert!(columns.windows(2).all(|pair| pair[0] == pair[1]), "{}", out);
+}
+
+/// Where the title starts: after the number, the id and their padding.
+fn title_column(line: &str) -> usize {
+    let id_start = line.find(|c: char| c.is_ascii_lowercase()).unwrap();
+    let id_end = id_start + line[id_start..].find(' ').unwrap();
+    id_end + line[id_end..].find(|c: char| c != ' ').unwrap()
+}
@@ -0,0 +1,30 @@
+//! Every snippet in `tests/compile_fail/` must be rejected by rustc with the
+//! error codes it declares.
+
+use std::path::Path;
+
+use rust_live_6_ownership::compile_fail;
+
+#[test]
+fn snippets_fail_with_the_expected_codes() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile_fail");
+    let cases = compile_fail::load_dir(&dir).expect("read tests/compile_fail");
+    assert!(
+        !cases.is_empty(),
+        "no compile-fail cases in {}",
+        dir.display()
+    );
+
+    let mut failures = Vec::new();
+    for case in &cases {
+        assert!(
+            !case.expected.is_empty(),
+            "{}: no `// error:` line",
+            case.name
+        );
+        if let Err(failure) = compile_fail::check(case) {
+            failures.push(format!("{}: {}", case.name, failure));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
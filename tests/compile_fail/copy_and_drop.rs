@@ -0,0 +1,12 @@
+// error: E0184
+// Section 11: a type that implements Drop cannot be Copy.
+#[derive(Clone, Copy)]
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+fn main() {
+    let _g = Guard;
+}
@@ -0,0 +1,15 @@
+// error: E0204
+// Section 11: a struct with a String field cannot derive Copy.
+#[derive(Clone, Copy)]
+struct Labeled {
+    name: String,
+    value: i32,
+}
+
+fn main() {
+    let a = Labeled {
+        name: String::from("a"),
+        value: 1,
+    };
+    let _b = a;
+}
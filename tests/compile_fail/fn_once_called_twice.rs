@@ -0,0 +1,13 @@
+// error: E0382
+// Section 14: a closure that moves what it captured can only be called once.
+fn main() {
+    let s = String::from("hello");
+
+    let consume = move || {
+        let owned = s;
+        owned.len()
+    };
+
+    consume();
+    consume();
+}
@@ -0,0 +1,13 @@
+// error: E0106
+// Section 20: the result could borrow from x or y, so it needs a lifetime.
+fn longest(x: &str, y: &str) -> &str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+fn main() {
+    println!("{}", longest("hello", "world"));
+}
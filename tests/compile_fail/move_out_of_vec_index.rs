@@ -0,0 +1,9 @@
+// error: E0507
+// Section 13: indexing borrows, so it cannot move the element out.
+fn main() {
+    let names = vec![String::from("a"), String::from("b")];
+
+    let first = names[0];
+
+    println!("{}", first);
+}
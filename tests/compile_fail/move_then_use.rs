@@ -0,0 +1,9 @@
+// error: E0382
+// Section 4: the line that has to stay commented out in the Move lesson.
+fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+
+    println!("{}, world!", s1); // This will not work
+    println!("{}", s2);
+}
@@ -0,0 +1,11 @@
+// error: E0502
+// Section 9: no mutable reference while shared ones are still in use.
+fn main() {
+    let mut s = String::from("hello");
+
+    let r1 = &s;
+    let r2 = &s;
+    let r3 = &mut s;
+
+    println!("{}, {}, and {}", r1, r2, r3);
+}
@@ -0,0 +1,13 @@
+// error: E0373
+// Section 15: the thread may outlive main's locals, so it must own them.
+use std::thread;
+
+fn main() {
+    let s = String::from("hello");
+
+    let handle = thread::spawn(|| {
+        println!("{}", s);
+    });
+
+    handle.join().unwrap();
+}
@@ -0,0 +1,11 @@
+// error: E0106
+// Section 20: a struct holding a reference must name the lifetime it borrows for.
+struct Excerpt {
+    part: &str,
+}
+
+fn main() {
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let excerpt = Excerpt { part: &novel[..15] };
+    println!("{}", excerpt.part);
+}
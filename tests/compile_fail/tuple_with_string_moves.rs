@@ -0,0 +1,8 @@
+// error: E0382
+// Section 11: (i32, String) is not Copy, so assignment moves it.
+fn main() {
+    let t1 = (5, String::from("hello"));
+    let t2 = t1;
+
+    println!("{:?} {:?}", t1, t2);
+}
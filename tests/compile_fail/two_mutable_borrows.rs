@@ -0,0 +1,10 @@
+// error: E0499
+// Section 9: only one mutable reference to a value at a time.
+fn main() {
+    let mut s = String::from("hello");
+
+    let r1 = &mut s;
+    let r2 = &mut s;
+
+    println!("{}, {}", r1, r2);
+}
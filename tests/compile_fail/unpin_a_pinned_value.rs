@@ -0,0 +1,18 @@
+// error: E0277
+// Section 21: a PhantomPinned value cannot be taken back out of its Pin.
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+struct Pinned {
+    text: String,
+    _pin: PhantomPinned,
+}
+
+fn main() {
+    let mut p1 = Box::pin(Pinned {
+        text: String::from("hello"),
+        _pin: PhantomPinned,
+    });
+    let inner: &mut Pinned = Pin::as_mut(&mut p1).get_mut();
+    inner.text.push_str(", world");
+}
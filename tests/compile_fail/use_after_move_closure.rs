@@ -0,0 +1,9 @@
+// error: E0382
+// Section 14: a `move` closure owns what it captures, even if it only reads it.
+fn main() {
+    let data = String::from("hello");
+
+    let read = move || data.len();
+
+    println!("{} {}", read(), data);
+}
@@ -0,0 +1,18 @@
+// error: E0382
+// Section 13: after a field is moved out, the struct as a whole is unusable.
+struct Person {
+    name: String,
+    email: String,
+}
+
+fn main() {
+    let person = Person {
+        name: String::from("Ferris"),
+        email: String::from("ferris@example.com"),
+    };
+
+    let name = person.name;
+    println!("{} {}", name, person.email); // fine: email was not moved
+
+    let whole = person;
+}
@@ -0,0 +1,16 @@
+// error: E0382
+// Section 15: sending a String down a channel moves it.
+use std::sync::mpsc;
+use std::thread;
+
+fn main() {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let message = String::from("hi");
+        tx.send(message).unwrap();
+        println!("{}", message);
+    });
+
+    println!("{}", rx.recv().unwrap());
+}
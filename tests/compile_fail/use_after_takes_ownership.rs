@@ -0,0 +1,22 @@
+// error: E0382
+// Section 7: s is used after its value moved into takes_ownership.
+fn main() {
+    let s = String::from("hello");
+
+    takes_ownership(s);
+
+    let x = 5;
+
+    makes_copy(x);
+
+    println!("{}", x); // fine: i32 is Copy
+    println!("{}", s); // s was moved
+}
+
+fn takes_ownership(some_string: String) {
+    println!("{}", some_string);
+}
+
+fn makes_copy(some_integer: i32) {
+    println!("{}", some_integer);
+}
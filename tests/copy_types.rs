@@ -0,0 +1,29 @@
+//! Which types are `Copy`, checked by the compiler: if one of the types
+//! below stopped being `Copy`, this file would not build. The types that
+//! must not be `Copy` are compile-fail cases in `tests/compile_fail/`.
+
+use rust_live_6_ownership::is_copy;
+use rust_live_6_ownership::lessons::copy_types::{Guard, Labeled, Point};
+
+fn assert_copy<T: Copy>() {}
+
+#[test]
+fn scalars_and_tuples_of_copy_types_are_copy() {
+    assert_copy::<u32>();
+    assert_copy::<bool>();
+    assert_copy::<f64>();
+    assert_copy::<char>();
+    assert_copy::<(i32, i32)>();
+    assert_copy::<&str>();
+    assert_copy::<Point>();
+}
+
+#[test]
+fn explorer_agrees_with_the_rules() {
+    assert!(is_copy!((i32, i32)));
+    assert!(is_copy!(Point));
+    assert!(!is_copy!((i32, String)));
+    assert!(!is_copy!(String));
+    assert!(!is_copy!(Labeled));
+    assert!(!is_copy!(Guard));
+}
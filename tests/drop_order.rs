@@ -0,0 +1,31 @@
+//! When destructors run, as taught by the Custom Drop lesson.
+
+use rust_live_6_ownership::lessons::drop;
+
+#[test]
+fn locals_drop_in_reverse_declaration_order() {
+    assert_eq!(drop::reverse_declaration_order(), ["c", "b", "a"]);
+}
+
+#[test]
+fn struct_drops_itself_then_its_fields_in_order() {
+    assert_eq!(drop::struct_field_order(), ["pair", "first", "second"]);
+}
+
+#[test]
+fn vec_drops_elements_first_to_last() {
+    assert_eq!(drop::vec_element_order(), ["v0", "v1", "v2"]);
+}
+
+#[test]
+fn temporaries_drop_at_the_end_of_their_statement() {
+    assert_eq!(
+        drop::temporaries(),
+        ["wildcard", "temporary", "(end of scope)", "kept"]
+    );
+}
+
+#[test]
+fn mem_drop_drops_early() {
+    assert_eq!(drop::mem_drop(), ["a", "b"]);
+}
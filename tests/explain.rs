@@ -0,0 +1,51 @@
+//! Every ownership rule points at a lesson and an article paragraph, and rustc
+//! diagnostics are matched to them.
+
+use rust_live_6_ownership::explain::{self, RULES};
+use rust_live_6_ownership::{article, find};
+
+#[test]
+fn every_rule_points_at_a_lesson_and_a_paragraph() {
+    for rule in RULES {
+        assert!(
+            find(rule.lesson).is_some(),
+            "{}: no lesson `{}`",
+            rule.code,
+            rule.lesson
+        );
+        assert!(
+            article::paragraph_containing(article::TEXT, rule.article).is_some(),
+            "{}: article.md has no paragraph containing {:?}",
+            rule.code,
+            rule.article
+        );
+    }
+}
+
+#[test]
+fn use_after_move_is_explained_by_the_move_lesson() {
+    let output = concat!(
+        r#"{"$message_type":"diagnostic","message":"borrow of moved value: `s1`","#,
+        r#""code":{"code":"E0382","explanation":"A variable was used after..."},"level":"error","#,
+        r#""spans":[{"file_name":"main.rs","line_start":7,"is_primary":true}],"#,
+        r#""children":[],"rendered":"error[E0382]: borrow of moved value: `s1`\n"}"#,
+        "\n",
+        r#"{"$message_type":"diagnostic","message":"aborting due to 1 previous error","#,
+        r#""code":null,"level":"error","spans":[],"children":[],"rendered":"..."}"#,
+    );
+
+    let diagnostics = explain::parse_diagnostics(output);
+    assert_eq!(diagnostics.len(), 2);
+
+    let explanations = explain::explain_output(output);
+    assert_eq!(explanations.len(), 1);
+    let explanation = &explanations[0];
+    assert_eq!(explanation.lesson.id, "move");
+    assert_eq!(explanation.diagnostic.line, Some(7));
+    assert!(explanation.fix.contains(".clone()"));
+    let article = explanation
+        .article
+        .as_deref()
+        .expect("a paragraph of the article");
+    assert!(article.contains("assigning a value to another variable moves it"));
+}
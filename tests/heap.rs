@@ -0,0 +1,50 @@
+//! Allocation counts of the lesson examples. Run with `--features count-alloc`.
+#![cfg(feature = "count-alloc")]
+
+use rust_live_6_ownership::heap::{self, HeapStats};
+use rust_live_6_ownership::lessons::{boxes, clone, copy, cow, moves, string_type};
+
+fn stats(example: fn()) -> HeapStats {
+    heap::measure(example).1.expect("counting is enabled")
+}
+
+#[test]
+fn move_allocates_one_buffer() {
+    let stats = stats(moves::example);
+    assert_eq!(stats.allocations, 1);
+    assert_eq!(stats.deallocations, 1);
+}
+
+#[test]
+fn clone_allocates_two_buffers() {
+    let stats = stats(clone::example);
+    assert_eq!(stats.allocations, 2);
+    assert_eq!(stats.deallocations, 2);
+}
+
+#[test]
+fn push_str_grows_the_buffer() {
+    let stats = stats(string_type::example);
+    assert_eq!(stats.allocations, 1);
+    assert_eq!(stats.reallocations, 1);
+}
+
+#[test]
+fn copy_stays_on_the_stack() {
+    assert_eq!(stats(copy::example), HeapStats::default());
+}
+
+#[test]
+fn every_box_is_one_allocation() {
+    // One Box<i32> plus the three boxes of a three-node list.
+    let stats = stats(boxes::example);
+    assert_eq!(stats.allocations, 4);
+    assert_eq!(stats.deallocations, 4);
+}
+
+#[test]
+fn cow_only_allocates_for_changed_inputs() {
+    // A clone per input, and the tab replacement on top of its clone.
+    assert_eq!(stats(cow::example_naive).allocations, cow::INPUTS.len() + 1);
+    assert_eq!(stats(cow::example).allocations, 1);
+}
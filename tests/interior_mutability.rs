@@ -0,0 +1,39 @@
+//! RefCell's run-time borrow check, as taught by the Interior Mutability
+//! lesson.
+
+use std::cell::RefCell;
+use std::thread;
+
+use rust_live_6_ownership::lessons::interior_mutability::{self, catch_quietly};
+
+#[test]
+fn the_lesson_catches_the_borrow_mut_panic() {
+    let transcript = interior_mutability::LESSON.run();
+    let caught = transcript
+        .lines()
+        .iter()
+        .find_map(|line| line.strip_prefix("borrow_mut panicked: "))
+        .expect("the lesson reports the caught panic");
+    assert!(caught.contains("already borrowed"), "{}", caught);
+}
+
+#[test]
+fn catch_quietly_returns_the_value_or_the_panic_message() {
+    let s = RefCell::new(String::from("hello"));
+    let reader = s.borrow();
+    assert_eq!(catch_quietly(|| s.borrow().len()), Ok(5));
+    let err = catch_quietly(|| s.borrow_mut().push('!')).unwrap_err();
+    assert!(err.contains("already borrowed"), "{}", err);
+    drop(reader);
+}
+
+#[test]
+fn catch_quietly_works_from_several_threads_at_once() {
+    let handles: Vec<_> = (0..4)
+        .map(|i| thread::spawn(move || catch_quietly(|| panic!("expected panic {}", i))))
+        .collect();
+    for (i, handle) in handles.into_iter().enumerate() {
+        let result = handle.join().expect("the panic was caught");
+        assert_eq!(result, Err(format!("expected panic {}", i)));
+    }
+}
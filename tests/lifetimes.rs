@@ -0,0 +1,20 @@
+//! The borrows of the Lifetimes lesson.
+
+use rust_live_6_ownership::lessons::lifetimes::{self, longest};
+
+#[test]
+fn longest_returns_the_longer_slice() {
+    let string1 = String::from("long string is long");
+    let string2 = String::from("xyz");
+    assert_eq!(longest(&string1, &string2), "long string is long");
+    assert_eq!(
+        lifetimes::first_sentence("Call me Ishmael. Some").part,
+        "Call me Ishmael"
+    );
+}
+
+#[test]
+fn only_the_dangling_program_outlives_its_owner() {
+    assert_eq!(lifetimes::dangling_timeline().dangling(), [("result", 6)]);
+    assert!(lifetimes::fixed_timeline().dangling().is_empty());
+}
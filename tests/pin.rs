@@ -0,0 +1,20 @@
+//! The pinned struct of the Pin lesson keeps pointing at itself.
+
+use rust_live_6_ownership::lessons::pin::{Pinned, SelfRef};
+
+#[test]
+fn a_pinned_value_stays_put_when_its_box_moves() {
+    let p1 = Pinned::new("hello");
+    let p2 = p1;
+    assert!(p2.points_at_self());
+    assert_eq!(p2.as_ref().text_via_ptr(), "hello");
+}
+
+#[test]
+fn an_unpinned_value_points_at_itself_only_until_it_moves() {
+    let mut s1 = SelfRef::new("hello");
+    s1.init();
+    assert!(s1.points_at_self());
+    let s2 = Box::new(s1);
+    assert!(!s2.points_at_self());
+}
@@ -0,0 +1,48 @@
+//! The lesson registry: numbering, ids, and a smoke run of every lesson.
+
+use std::collections::HashSet;
+
+use rust_live_6_ownership::{find, registry};
+
+#[test]
+fn lessons_are_numbered_by_their_position() {
+    for (i, lesson) in registry().iter().enumerate() {
+        assert_eq!(
+            lesson.number,
+            i + 1,
+            "lesson `{}` is out of place",
+            lesson.id
+        );
+    }
+}
+
+#[test]
+fn ids_are_unique_and_non_empty() {
+    let mut seen = HashSet::new();
+    for lesson in registry() {
+        assert!(!lesson.id.is_empty(), "lesson {} has no id", lesson.number);
+        assert!(seen.insert(lesson.id), "duplicate id `{}`", lesson.id);
+    }
+}
+
+#[test]
+fn find_accepts_ids_and_numbers() {
+    for lesson in registry() {
+        assert_eq!(find(lesson.id).map(|l| l.number), Some(lesson.number));
+        assert_eq!(
+            find(&lesson.number.to_string()).map(|l| l.id),
+            Some(lesson.id)
+        );
+    }
+}
+
+#[test]
+fn every_lesson_runs() {
+    for lesson in registry() {
+        assert!(
+            !lesson.run().lines().is_empty(),
+            "lesson `{}` wrote nothing",
+            lesson.id
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//! The Return Values lesson: its drop order matches what the article says, and
+//! `calculate_length` hands ownership back.
+
+use rust_live_6_ownership::article;
+use rust_live_6_ownership::lessons::return_values;
+
+/// The variables the article's closing comment says are dropped, in the
+/// order it says so: "Here, s3 goes out of scope and is dropped. s2 was
+/// moved, so nothing happens. s1 goes out of scope and is dropped."
+fn claimed_order() -> Vec<String> {
+    let block = article::code_blocks(article::TEXT)
+        .into_iter()
+        .find(|block| block.heading == "Return Values and Scope")
+        .expect("article has a Return Values block");
+    let comment: String = block
+        .code
+        .lines()
+        .skip_while(|line| !line.starts_with("} // Here,"))
+        .take_while(|line| !line.is_empty())
+        .map(|line| line.trim_start_matches(['}', ' ', '/']))
+        .collect::<Vec<_>>()
+        .join(" ");
+    comment
+        .split('.')
+        .filter(|sentence| sentence.contains("is dropped"))
+        .filter_map(|sentence| {
+            sentence
+                .split_whitespace()
+                .find(|word| word.starts_with('s') && word[1..].parse::<u8>().is_ok())
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+#[test]
+fn drop_order_matches_the_article() {
+    assert_eq!(claimed_order(), ["s3", "s1"]);
+    assert_eq!(return_values::drop_order(), claimed_order());
+}
+
+#[test]
+fn calculate_length_hands_the_string_back() {
+    let (s, len) = return_values::calculate_length(String::from("hello"));
+    assert_eq!((s.as_str(), len), ("hello", 5));
+}
@@ -0,0 +1,13 @@
+//! Reference cycles leak, as taught by the Shared Ownership lesson.
+
+use rust_live_6_ownership::lessons::shared_ownership;
+
+#[test]
+fn a_strong_cycle_leaks_both_nodes() {
+    assert_eq!(shared_ownership::strong_cycle(), ["a", "b"]);
+}
+
+#[test]
+fn a_weak_back_link_frees_both_nodes() {
+    assert!(shared_ownership::weak_back_link().is_empty());
+}
@@ -0,0 +1,28 @@
+//! The article's code blocks match the lesson snippets, and `rewrite` repairs drift.
+
+use rust_live_6_ownership::{article, sync};
+
+#[test]
+fn article_matches_the_lessons() {
+    let drift: Vec<String> = sync::check(article::TEXT)
+        .iter()
+        .filter(|difference| difference.is_drift())
+        .map(ToString::to_string)
+        .collect();
+    assert!(drift.is_empty(), "\n{}", drift.join("\n"));
+}
+
+#[test]
+fn rewrite_repairs_drift() {
+    let edited = article::TEXT.replace("let s2 = s1.clone();", "let s2 = s1;");
+    let drift: Vec<_> = sync::check(&edited)
+        .into_iter()
+        .filter(|difference| difference.is_drift())
+        .collect();
+    assert_eq!(drift.len(), 1, "{:?}", drift);
+
+    let repaired = sync::rewrite(&edited);
+    assert!(repaired.contains("let s2 = s1.clone();"));
+    assert!(sync::check(&repaired).iter().all(|d| !d.is_drift()));
+    assert_eq!(sync::rewrite(&repaired), repaired);
+}
@@ -0,0 +1,23 @@
+//! The Threads lesson records which thread drops each value.
+
+use std::thread;
+
+use rust_live_6_ownership::lessons::threads;
+
+#[test]
+fn each_value_is_dropped_by_the_thread_that_owns_it() {
+    let here = thread::current().name().unwrap_or("<unnamed>").to_string();
+    let transcript = threads::LESSON.run();
+    let lines = transcript.lines();
+    let dropped_on = |label: &str| -> Vec<&str> {
+        let prefix = format!("{} was dropped on thread ", label);
+        lines
+            .iter()
+            .filter_map(|line| line.strip_prefix(&prefix))
+            .collect()
+    };
+
+    assert_eq!(dropped_on("worker's s"), ["worker"]);
+    assert_eq!(dropped_on("main's s"), [here.as_str()]);
+    assert_eq!(dropped_on("received"), [here.as_str(), here.as_str()]);
+}